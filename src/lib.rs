@@ -1,15 +1,19 @@
 //! Rust version of Agner Fog's [vectorclass lib](https://github.com/vectorclass/version2)
 //!
-//! This crate contains a struct that containts four packed `f32` values and uses SIMD instructions
-//! to work with them
+//! This crate contains structs that contain four packed `f32` values, two packed `f64` values and
+//! four packed `i32` values and uses SIMD instructions to work with them
 //!
 //! This crate can only be compiled on `86` or `x86_64` architecture and a proccessor that supports at
 //! least `sse2` instruction set
 //!
-//! This crate also has `no_std` attribute
+//! This crate also has `no_std` attribute. Methods that need allocation are available with the
+//! `alloc` feature
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 compile_error!("This crate is only supported for x86 and x86_64 architecture");
 
@@ -32,7 +36,27 @@ mod vectorf128;
     any(target_arch = "x86", target_arch = "x86_64"),
     target_feature = "sse2"
 ))]
-pub use vectorf128::Vec4f;
+pub use vectorf128::{Align16, AlignedBuffer4, DotAccumulator, ParseVec4fError, Vec4f, Vec4fb};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod vectorf128d;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+pub use vectorf128d::Vec2d;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod vectori128;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+pub use vectori128::Vec4i;
 
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
@@ -40,11 +64,20 @@ pub use vectorf128::Vec4f;
     test
 ))]
 mod tests {
-    use crate::Vec4f;
+    use crate::{
+        Align16, AlignedBuffer4, DotAccumulator, ParseVec4fError, Vec2d, Vec4f, Vec4fb, Vec4i,
+    };
+
+    const _: () = assert!(Vec4f::BYTES == 16);
+    const _: () = assert!(Vec4f::ALIGN == 16);
+    const _: () = assert!(Vec4f::SCALAR_BITS * Vec4f::LEN == Vec4f::BYTES * 8);
 
     #[test]
     fn test_basic() {
         assert_eq!(Vec4f::LEN, 4);
+        assert_eq!(Vec4f::BYTES, core::mem::size_of::<Vec4f>());
+        assert_eq!(Vec4f::ALIGN, core::mem::align_of::<Vec4f>());
+        assert_eq!(Vec4f::SCALAR_BITS, core::mem::size_of::<f32>() * 8);
 
         let a = Vec4f::from_scalar(32.0);
         let b = Vec4f::new(1.0, 2.0, 3.0, 4.0);
@@ -223,7 +256,7 @@ mod tests {
     #[should_panic(expected = "Index out of bounds")]
     fn test_index_panic() {
         let a = Vec4f::default();
-        a[5];
+        let _ = a[5];
     }
 
     //Selects aligned buffer
@@ -296,4 +329,1559 @@ mod tests {
         assert_eq!(e.cutoff(1), [-3.0, 0.0, 0.0, 0.0]);
         assert_eq!(e.cutoff(0), [0.0, 0.0, 0.0, 0.0]);
     }
+
+    #[test]
+    fn test_checked_horizontal_add() {
+        let a = Vec4f::new(1.0, 2.0, 3.0, -4.0);
+        assert_eq!(a.try_horizontal_add(), 2.0);
+        assert_eq!(a.checked_horizontal_add(), Some(2.0));
+
+        let b = Vec4f::new(1.0, 2.0, f32::NAN, -4.0);
+        assert!(b.try_horizontal_add().is_nan());
+        assert_eq!(b.checked_horizontal_add(), None);
+
+        let c = Vec4f::new(f32::NAN, 2.0, f32::NAN, -4.0);
+        assert_eq!(c.checked_horizontal_add(), None);
+    }
+
+    #[test]
+    fn test_swap_pairs() {
+        let a = Vec4f::new(1.0, -2.0, 3.5, 4.0);
+        assert_eq!(a.swap_pairs(), [-2.0, 1.0, 4.0, 3.5]);
+        assert_eq!(a.swap_pairs().swap_pairs(), a);
+    }
+
+    #[test]
+    fn test_movehl_movelh() {
+        let a = Vec4f::new(-1.0, 2.0, 5.0, -6.0);
+        assert_eq!(a.movehl(), [5.0, -6.0, 5.0, -6.0]);
+        assert_eq!(a.movelh(), [-1.0, 2.0, -1.0, 2.0]);
+        assert_eq!((a + a.movehl()).movelh(), [4.0, -4.0, 4.0, -4.0]);
+    }
+
+    #[test]
+    fn test_clamped_arithmetic() {
+        let a = Vec4f::new(0.5, -0.5, 0.25, 0.0);
+        let b = Vec4f::new(0.75, -0.75, 0.25, 1.0);
+        assert_eq!(a.add_clamped(b, -1.0, 1.0), [1.0, -1.0, 0.5, 1.0]);
+        assert_eq!(a.sub_clamped(b, -1.0, 1.0), [-0.25, 0.25, 0.0, -1.0]);
+
+        let mut acc = Vec4f::default();
+        for _ in 0..10 {
+            acc = acc.add_clamped(Vec4f::new(0.3, -0.3, 0.1, -0.1), -2.0, 2.0);
+        }
+        compare_approx_vec4f(&acc, [2.0, -2.0, 1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_dot_accumulator() {
+        let mut a = [0.0f32; 1000];
+        let mut b = [0.0f32; 1000];
+        for i in 0..1000 {
+            a[i] = (i % 7) as f32 - 3.0;
+            b[i] = (i % 5) as f32 * 0.5;
+        }
+
+        let mut naive = 0.0f32;
+        for i in 0..1000 {
+            naive += a[i] * b[i];
+        }
+
+        let mut acc = DotAccumulator::new();
+        for (x, y) in a.chunks(4).zip(b.chunks(4)) {
+            acc.accumulate(Vec4f::from(x), Vec4f::from(y));
+        }
+        assert!(f32::abs(acc.finish() - naive) < 0.001);
+
+        assert_eq!(DotAccumulator::default().finish(), 0.0);
+    }
+
+    #[test]
+    fn test_mask() {
+        let a = Vec4fb::new(true, false, true, false);
+        assert_eq!(a.to_bits(), 0b0101);
+        assert!(a.any());
+        assert!(!a.all());
+        assert!((a | !a).all());
+        assert!(!(a & !a).any());
+        assert_eq!(
+            a ^ Vec4fb::new(true, true, true, true),
+            [false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_approx_eq_mask() {
+        let a = Vec4f::new(1.0, 2.0, -3.0, 4.0);
+        let b = Vec4f::new(1.0005, 2.1, -3.0005, 3.0);
+        assert_eq!(a.approx_eq_mask(b, 0.001), [true, false, true, false]);
+        assert!(a.approx_eq_mask(b, 1.0).all());
+        assert!(!a.approx_eq_mask(b, 0.0).any());
+
+        let c = Vec4f::new(f32::NAN, 2.0, -3.0, 4.0);
+        assert_eq!(a.approx_eq_mask(c, 0.1), [false, true, true, true]);
+    }
+
+    #[test]
+    fn test_round_to_multiple() {
+        let a = Vec4f::new(1.1, 2.6, -0.4, 3.5);
+        assert_eq!(a.round_to_multiple(0.5), [1.0, 2.5, -0.5, 3.5]);
+        assert_eq!(a.round_to_multiple(2.0), [2.0, 2.0, -0.0, 4.0]);
+        assert_eq!(a.round_to_multiple(-0.5), [1.0, 2.5, -0.5, 3.5]);
+        assert_eq!(a.round_to_multiple(0.0), a);
+    }
+
+    #[test]
+    fn test_cmp_ordered() {
+        let a = Vec4f::new(1.0, f32::NAN, 3.0, f32::NAN);
+        let b = Vec4f::new(-1.0, 2.0, f32::NAN, f32::NAN);
+        assert_eq!(a.cmp_ordered(b), [true, false, false, false]);
+        assert_eq!(a.cmp_unordered(b), [false, true, true, true]);
+        assert_eq!(b.cmp_unordered(a), a.cmp_unordered(b));
+
+        let c = Vec4f::new(f32::INFINITY, -0.0, 0.0, f32::NEG_INFINITY);
+        assert!(c.cmp_ordered(c).all());
+        assert!(!c.cmp_unordered(c).any());
+    }
+
+    #[test]
+    fn test_div_safe() {
+        let a = Vec4f::new(1.0, -2.0, 3.0, 0.0);
+        let b = Vec4f::new(0.0, 4.0, -0.0, 2.0);
+        assert_eq!(a.div_safe(b, 100.0), [100.0, -0.5, 100.0, 0.0]);
+        assert_eq!(
+            a.div_safe(Vec4f::from_scalar(2.0), 100.0),
+            a / Vec4f::from_scalar(2.0)
+        );
+        assert_eq!(a.div_safe(Vec4f::default(), 7.0), [7.0; 4]);
+    }
+
+    #[test]
+    fn test_normalize_assign() {
+        let mut a = Vec4f::new(1.0, -2.0, 3.0, -4.0);
+        a.normalize_assign();
+        assert!(f32::abs(a.squared().horizontal_add() - 1.0) < 0.0001);
+        let length = f32::sqrt(30.0);
+        compare_approx_vec4f(
+            &a,
+            [1.0 / length, -2.0 / length, 3.0 / length, -4.0 / length],
+        );
+
+        let mut b = Vec4f::default();
+        b.normalize_assign();
+        assert_eq!(b, [0.0; 4]);
+    }
+
+    #[test]
+    fn test_mask_array() {
+        for bits in 0..16u8 {
+            let bools = [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0];
+            let mask = Vec4fb::from_array(bools);
+            assert_eq!(mask.to_bits(), bits);
+            assert_eq!(mask.to_array(), bools);
+            assert_eq!(Vec4fb::from_array(mask.to_array()), mask);
+        }
+    }
+
+    #[test]
+    fn test_select_or() {
+        let a = Vec4f::new(1.0, -2.0, 3.0, -4.0);
+        let mask = Vec4fb::new(false, true, true, false);
+        assert_eq!(
+            Vec4f::select_or(mask, a, f32::MAX),
+            [f32::MAX, -2.0, 3.0, f32::MAX]
+        );
+        assert_eq!(Vec4f::select_or(!mask, a, 0.0), [1.0, 0.0, 0.0, -4.0]);
+        assert_eq!(Vec4f::select_or(mask | !mask, a, 0.0), a);
+    }
+
+    #[test]
+    fn test_horizontal_add_ignore_nan() {
+        let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+        assert_eq!(a.horizontal_add_ignore_nan(), 6.0);
+        assert_eq!(a.horizontal_add_ignore_nan_count(), (6.0, 3));
+
+        let b = Vec4f::new(f32::NAN, -2.0, f32::NAN, 0.5);
+        assert_eq!(b.horizontal_add_ignore_nan(), -1.5);
+        assert_eq!(b.horizontal_add_ignore_nan_count(), (-1.5, 2));
+
+        let c = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(c.horizontal_add_ignore_nan_count(), (10.0, 4));
+        assert_eq!(
+            Vec4f::from_scalar(f32::NAN).horizontal_add_ignore_nan_count(),
+            (0.0, 0)
+        );
+    }
+
+    #[test]
+    fn test_one_hot() {
+        assert_eq!(Vec4f::one_hot(0, 1.5), [1.5, 0.0, 0.0, 0.0]);
+        assert_eq!(Vec4f::one_hot(1, -2.0), [0.0, -2.0, 0.0, 0.0]);
+        assert_eq!(Vec4f::one_hot(2, 3.0), [0.0, 0.0, 3.0, 0.0]);
+        assert_eq!(Vec4f::one_hot(3, 4.0), [0.0, 0.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_one_hot_panic() {
+        Vec4f::one_hot(4, 1.0);
+    }
+
+    #[test]
+    fn test_aligned_array() {
+        let a = Vec4f::new(-1.0, 2.0, -3.0, 4.0);
+        let arrays = [a.to_aligned_array(), (-a).to_aligned_array()];
+        for arr in arrays.iter() {
+            assert_eq!(arr.0.as_ptr() as usize % 16, 0);
+        }
+        assert_eq!(arrays[0], Align16([-1.0, 2.0, -3.0, 4.0]));
+        assert_eq!(arrays[1], Align16([1.0, -2.0, 3.0, -4.0]));
+
+        let mut b = Vec4f::default();
+        b.load_aligned(&arrays[0].0);
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    fn test_change_sign_runtime() {
+        let a = Vec4f::new(-2.0, 3.0, 0.0, -1.0);
+        assert_eq!(
+            a.change_sign_runtime([true, false, true, false]),
+            a.change_sign::<true, false, true, false>()
+        );
+        assert_eq!(
+            a.change_sign_runtime([false, true, false, true]),
+            a.change_sign::<false, true, false, true>()
+        );
+        assert_eq!(
+            a.change_sign_runtime([true, true, true, true]),
+            a.change_sign::<true, true, true, true>()
+        );
+        assert_eq!(a.change_sign_runtime([false; 4]), a);
+    }
+
+    #[test]
+    fn test_horizontal_bits() {
+        let bits: [u32; 4] = [0x0000_00FF, 0x0000_0F0F, 0x0000_F00F, 0x1234_000F];
+        let a = Vec4f::new(
+            f32::from_bits(bits[0]),
+            f32::from_bits(bits[1]),
+            f32::from_bits(bits[2]),
+            f32::from_bits(bits[3]),
+        );
+        assert_eq!(
+            a.horizontal_and_bits(),
+            bits[0] & bits[1] & bits[2] & bits[3]
+        );
+        assert_eq!(
+            a.horizontal_or_bits(),
+            bits[0] | bits[1] | bits[2] | bits[3]
+        );
+        assert_eq!(
+            a.horizontal_xor_bits(),
+            bits[0] ^ bits[1] ^ bits[2] ^ bits[3]
+        );
+
+        let b = Vec4f::new(-1.0, -2.0, -0.5, -4.0);
+        assert_eq!(b.horizontal_and_bits() & 0x8000_0000, 0x8000_0000);
+        assert_eq!(Vec4f::default().horizontal_or_bits(), 0);
+    }
+
+    #[test]
+    fn test_horizontal_masked() {
+        let a = Vec4f::new(-5.0, 2.0, 10.0, -1.0);
+        let mask = Vec4fb::new(false, true, false, true);
+        assert_eq!(a.horizontal_max_masked(mask), 2.0);
+        assert_eq!(a.horizontal_min_masked(mask), -1.0);
+        assert_eq!(a.horizontal_max_masked(!mask), 10.0);
+        assert_eq!(a.horizontal_min_masked(!mask), -5.0);
+
+        let none = Vec4fb::new(false, false, false, false);
+        assert_eq!(a.horizontal_max_masked(none), f32::NEG_INFINITY);
+        assert_eq!(a.horizontal_min_masked(none), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_store_interleaved() {
+        let x = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        let y = Vec4f::new(-1.0, -2.0, -3.0, -4.0);
+        let z = Vec4f::new(0.5, 1.5, 2.5, 3.5);
+        let w = Vec4f::new(10.0, 20.0, 30.0, 40.0);
+        let mut out = [0.0f32; 17];
+        Vec4f::store_interleaved4(x, y, z, w, &mut out);
+        for i in 0..4 {
+            assert_eq!(out[4 * i..4 * i + 4], [x[i], y[i], z[i], w[i]]);
+        }
+        assert_eq!(out[16], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len not enough")]
+    fn test_store_interleaved_panic() {
+        let mut out = [0.0f32; 15];
+        let a = Vec4f::default();
+        Vec4f::store_interleaved4(a, a, a, a, &mut out);
+    }
+
+    #[test]
+    fn test_load_interleaved() {
+        let mut data = [0.0f32; 16];
+        for (i, value) in data.iter_mut().enumerate() {
+            *value = i as f32;
+        }
+        let (x, y, z, w) = Vec4f::load_interleaved4(&data);
+        assert_eq!(x, [0.0, 4.0, 8.0, 12.0]);
+        assert_eq!(y, [1.0, 5.0, 9.0, 13.0]);
+        assert_eq!(z, [2.0, 6.0, 10.0, 14.0]);
+        assert_eq!(w, [3.0, 7.0, 11.0, 15.0]);
+
+        let mut out = [0.0f32; 16];
+        Vec4f::store_interleaved4(x, y, z, w, &mut out);
+        assert_eq!(out, data);
+        assert_eq!(Vec4f::load_interleaved4(&out), (x, y, z, w));
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len not enough")]
+    fn test_load_interleaved_panic() {
+        Vec4f::load_interleaved4(&[0.0f32; 12]);
+    }
+
+    #[test]
+    fn test_store_exact() {
+        let a = Vec4f::new(-1.0, 2.0, -3.0, 4.0);
+        let mut buffer = [0.0f32; 6];
+        a.store_exact(&mut buffer[1..5]);
+        assert_eq!(buffer, [0.0, -1.0, 2.0, -3.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len is not equal to vector size")]
+    fn test_store_exact_larger_panic() {
+        let mut buffer = [0.0f32; 5];
+        Vec4f::default().store_exact(&mut buffer);
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len is not equal to vector size")]
+    fn test_store_exact_smaller_panic() {
+        let mut buffer = [0.0f32; 3];
+        Vec4f::default().store_exact(&mut buffer);
+    }
+
+    #[test]
+    fn test_nonzero_mask() {
+        assert_eq!(Vec4f::new(0.0, -0.0, 0.0, -0.0).nonzero_mask(), 0);
+        assert_eq!(Vec4f::new(1.0, 2.0, -3.0, 4.0).nonzero_mask(), 0b1111);
+        assert_eq!(
+            Vec4f::new(f32::MIN_POSITIVE, -0.0, -f32::from_bits(1), 0.0).nonzero_mask(),
+            0b0101
+        );
+        assert_eq!(Vec4f::new(0.0, f32::NAN, 0.0, 0.0).nonzero_mask(), 0b0010);
+    }
+
+    #[test]
+    fn test_clamp_reported() {
+        let a = Vec4f::new(-5.0, 0.0, 2.5, 7.0);
+        let lo = Vec4f::new(-1.0, -1.0, 0.0, 0.0);
+        let hi = Vec4f::new(1.0, 1.0, 2.5, 5.0);
+        let (clamped, mask) = a.clamp_reported(lo, hi);
+        assert_eq!(clamped, [-1.0, 0.0, 2.5, 5.0]);
+        assert_eq!(mask, [true, false, false, true]);
+
+        let (clamped, mask) = a.clamp_reported(Vec4f::from_scalar(-10.0), Vec4f::from_scalar(10.0));
+        assert_eq!(clamped, a);
+        assert!(!mask.any());
+    }
+
+    #[test]
+    fn test_fast_powers() {
+        let a = Vec4f::new(-1.5, 2.0, 3.0, 0.25);
+        assert_eq!(a.cubed(), a.pow(3));
+        assert_eq!(
+            a.cubed(),
+            [-1.5 * -1.5 * -1.5, 8.0, 27.0, 0.25 * 0.25 * 0.25]
+        );
+        assert_eq!(a.pow(2), a.squared());
+        assert_eq!(a.pow(-1), [1.0 / -1.5, 0.5, 1.0 / 3.0, 4.0]);
+        assert_eq!(a.pow(-3), Vec4f::from_scalar(1.0) / a.cubed());
+
+        let b = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        compare_approx_vec4f(
+            &b.recip_sqrt(),
+            [1.0, 1.0 / f32::sqrt(2.0), 1.0 / f32::sqrt(3.0), 0.5],
+        );
+    }
+
+    #[test]
+    fn test_is_subnormal() {
+        let subnormal = f32::from_bits(0x0000_1234);
+        let a = Vec4f::new(subnormal, -subnormal, f32::MIN_POSITIVE, -0.0);
+        assert_eq!(a.is_subnormal(), [true, true, false, false]);
+
+        let b = Vec4f::new(1.0, f32::INFINITY, f32::NAN, f32::MAX);
+        assert!(!b.is_subnormal().any());
+    }
+
+    #[test]
+    fn test_relu() {
+        let a = Vec4f::new(-2.0, 0.0, 3.5, -0.25);
+        assert_eq!(a.relu(), [0.0, 0.0, 3.5, 0.0]);
+        assert_eq!(a.leaky_relu(0.1), [-0.2, 0.0, 3.5, -0.025]);
+        assert_eq!(a.leaky_relu(0.0), a.relu());
+        assert_eq!(a.leaky_relu(1.0), a);
+    }
+
+    #[test]
+    fn test_tanh_fast() {
+        let mut previous = -1.0f32;
+        for i in -800..=800 {
+            let x = i as f32 * 0.00625;
+            let tanh = Vec4f::new(x, -x, x * 2.0, 0.0).tanh_fast();
+            let tolerance = if x.abs() <= 0.3 { 1e-3 } else { 2.5e-2 };
+            assert!(f32::abs(tanh[0] - f32::tanh(x)) < tolerance);
+            assert!(f32::abs(tanh[2] - f32::tanh(x * 2.0)) < 2.5e-2);
+            assert_eq!(tanh[1], -tanh[0]);
+            assert!(tanh[0] >= previous);
+            assert!(tanh[0].abs() <= 1.0);
+            previous = tanh[0];
+        }
+        let saturated = Vec4f::new(3.0, -3.0, 100.0, f32::NEG_INFINITY).tanh_fast();
+        assert_eq!(saturated, [1.0, -1.0, 1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_dot_broadcast() {
+        let a = Vec4f::new(1.5, -2.0, 3.0, 0.5);
+        let b = Vec4f::new(2.0, 1.0, -1.0, 4.0);
+        let dot = 1.5 * 2.0 - 2.0 * 1.0 - 3.0 * 1.0 + 0.5 * 4.0;
+        assert_eq!(Vec4f::dot_broadcast(a, b), [dot; 4]);
+        assert_eq!(Vec4f::dot_broadcast(a, b)[0], (a * b).horizontal_add());
+        assert_eq!(Vec4f::dot_broadcast(a, Vec4f::default()), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_finite_or_zero() {
+        let a = Vec4f::new(f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -3.5);
+        assert_eq!(a.is_finite(), [false, false, false, true]);
+        assert_eq!(a.finite_or_zero(), [0.0, 0.0, 0.0, -3.5]);
+
+        let b = Vec4f::new(f32::MAX, f32::MIN, f32::from_bits(1), -0.0);
+        assert!(b.is_finite().all());
+        assert_eq!(b.finite_or_zero(), b);
+    }
+
+    #[test]
+    fn test_copysign_scalar() {
+        let a = Vec4f::new(-2.0, 3.0, -0.5, 4.0);
+        assert_eq!(a.copysign_scalar(-7.0), [-2.0, -3.0, -0.5, -4.0]);
+        assert_eq!(a.copysign_scalar(0.0), [2.0, 3.0, 0.5, 4.0]);
+
+        let b = Vec4f::new(0.0, 1.0, -1.0, -0.0).copysign_scalar(-0.0);
+        assert_eq!(b.horizontal_and_bits() & 0x8000_0000, 0x8000_0000);
+        assert_eq!(b, [-0.0, -1.0, -1.0, -0.0]);
+    }
+
+    #[test]
+    fn test_iota() {
+        assert_eq!(Vec4f::iota(0.0, 1.0), [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(Vec4f::iota(2.0, -0.5), [2.0, 1.5, 1.0, 0.5]);
+        assert_eq!(Vec4f::iota(-1.0, 0.0), [-1.0; 4]);
+    }
+
+    #[test]
+    fn test_lane_indices() {
+        let indices = Vec4f::lane_indices();
+        assert_eq!(indices, [0.0, 1.0, 2.0, 3.0]);
+        for i in 0..4 {
+            assert_eq!(indices[i], i as f32);
+        }
+        assert_eq!(indices, Vec4f::iota(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        let first = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        let second = Vec4f::new(-1.0, -2.0, -3.0, -4.0);
+        let mask = Vec4fb::new(true, false, true, false);
+
+        let mut a = first;
+        let mut b = second;
+        Vec4f::conditional_swap(&mut a, &mut b, mask);
+        assert_eq!(a, [-1.0, 2.0, -3.0, 4.0]);
+        assert_eq!(b, [1.0, -2.0, 3.0, -4.0]);
+
+        Vec4f::conditional_swap(&mut a, &mut b, !mask);
+        assert_eq!(a, second);
+        assert_eq!(b, first);
+    }
+
+    #[test]
+    fn test_minmax() {
+        let a = Vec4f::new(3.0, -1.0, 0.0, 7.5);
+        let b = Vec4f::new(-3.0, 2.0, 0.0, 7.0);
+        let (lo, hi) = Vec4f::minmax(a, b);
+        assert_eq!(lo, [-3.0, -1.0, 0.0, 7.0]);
+        assert_eq!(hi, [3.0, 2.0, 0.0, 7.5]);
+        assert_eq!(Vec4f::minmax(b, a), (lo, hi));
+        assert_eq!(lo + hi, a + b);
+    }
+
+    #[test]
+    fn test_all_in_range() {
+        assert!(Vec4f::new(-0.5, 0.0, 0.25, 0.5).all_in_range(-1.0, 1.0));
+        assert!(!Vec4f::new(-0.5, 0.0, 1.25, 0.5).all_in_range(-1.0, 1.0));
+        assert!(!Vec4f::new(-1.5, 0.0, 0.25, 0.5).all_in_range(-1.0, 1.0));
+        assert!(Vec4f::new(-1.0, 1.0, -1.0, 1.0).all_in_range(-1.0, 1.0));
+        assert!(!Vec4f::new(-1.0, f32::NAN, -1.0, 1.0).all_in_range(-1.0, 1.0));
+        assert!(!Vec4f::default().all_in_range(1.0, -1.0));
+    }
+
+    #[test]
+    fn test_window_sum2() {
+        assert_eq!(
+            Vec4f::new(1.0, 2.0, 3.0, 4.0).window_sum2(),
+            [3.0, 5.0, 7.0, 4.0]
+        );
+        assert_eq!(
+            Vec4f::new(-1.0, 1.0, -0.5, -2.0).window_sum2(),
+            [0.0, 0.5, -2.5, -2.0]
+        );
+    }
+
+    #[test]
+    fn test_is_aligned() {
+        let mut buffer = [0.0f32; 7];
+        let aligned = select_aligned(&mut buffer);
+        assert!(Vec4f::is_aligned(aligned));
+        assert!(!Vec4f::is_aligned(&aligned[1..]));
+        assert!(!Vec4f::is_aligned(&aligned[2..]));
+        assert!(!Vec4f::is_aligned(&aligned[3..]));
+
+        let arr = Align16([1.0f32; 8]);
+        assert!(Vec4f::is_aligned(&arr.0[4..]));
+    }
+
+    #[test]
+    fn test_load_auto() {
+        let mut buffer = [0.0f32; 7];
+        let aligned = select_aligned(&mut buffer);
+        aligned.copy_from_slice(&[1.0, -2.0, 3.0, -4.0]);
+
+        let mut a = Vec4f::default();
+        a.load_auto(aligned);
+        let mut b = Vec4f::default();
+        b.load(aligned);
+        assert_eq!(a, b);
+
+        let data = [0.0f32, 1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0];
+        for offset in 0..4 {
+            let mut c = Vec4f::default();
+            c.load_auto(&data[offset..]);
+            assert_eq!(c, Vec4f::from(&data[offset..]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len not enough")]
+    fn test_load_auto_panic() {
+        let mut a = Vec4f::default();
+        a.load_auto(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_store_auto() {
+        let a = Vec4f::new(1.0, -2.0, 3.0, -4.0);
+
+        let mut buffer = [0.0f32; 7];
+        let aligned = select_aligned(&mut buffer);
+        a.store_auto(aligned);
+        assert_eq!(aligned, [1.0, -2.0, 3.0, -4.0]);
+
+        for offset in 0..4 {
+            let mut data = [0.0f32; 8];
+            a.store_auto(&mut data[offset..]);
+            assert_eq!(data[offset..offset + 4], [1.0, -2.0, 3.0, -4.0]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer len not enough")]
+    fn test_store_auto_panic() {
+        let mut buffer = [0.0f32; 3];
+        Vec4f::default().store_auto(&mut buffer);
+    }
+
+    #[test]
+    fn test_div_or_nan() {
+        let a = Vec4f::new(1.0, 1.0, -2.0, 3.0);
+        let b = Vec4f::new(0.0009, 0.0011, -0.0009, -0.0011);
+        let c = a.div_or_nan(b, 0.001);
+        assert!(c[0].is_nan());
+        assert_eq!(c[1], 1.0 / 0.0011);
+        assert!(c[2].is_nan());
+        assert_eq!(c[3], 3.0 / -0.0011);
+
+        let d = Vec4f::new(2.0, 4.0, -8.0, 1.0);
+        assert_eq!(a.div_or_nan(d, 0.001), a / d);
+    }
+
+    #[test]
+    fn test_geometric_mean() {
+        let a = Vec4f::new(1.0, 2.0, 4.0, 8.0);
+        assert!(f32::abs(a.geometric_mean() - f32::powf(64.0, 0.25)) < 0.00001);
+        assert_eq!(Vec4f::from_scalar(3.0).geometric_mean(), 3.0);
+        assert_eq!(Vec4f::new(0.0, 2.0, 4.0, 8.0).geometric_mean(), 0.0);
+        assert!(Vec4f::new(1.0, 2.0, 4.0, -8.0).geometric_mean().is_nan());
+        assert!(Vec4f::new(-1.0, -2.0, 4.0, 8.0).geometric_mean().is_nan());
+    }
+
+    #[test]
+    fn test_max3_min3() {
+        let a = Vec4f::new(3.0, -1.0, 0.0, 2.0);
+        let b = Vec4f::new(1.0, 4.0, -5.0, 2.0);
+        let c = Vec4f::new(2.0, 0.0, 6.0, -2.0);
+        assert_eq!(Vec4f::max3(a, b, c), [3.0, 4.0, 6.0, 2.0]);
+        assert_eq!(Vec4f::min3(a, b, c), [1.0, -1.0, -5.0, -2.0]);
+        assert_eq!(Vec4f::max3(c, a, b), Vec4f::max3(a, b, c));
+        assert_eq!(Vec4f::min3(b, c, a), Vec4f::min3(a, b, c));
+    }
+
+    #[test]
+    fn test_fold_lanes() {
+        let a = Vec4f::new(-3.0, 7.0, 2.0, 5.0);
+        let all = Vec4fb::new(true, true, true, true);
+        assert_eq!(
+            a.fold_lanes(f32::NEG_INFINITY, f32::max),
+            a.horizontal_max_masked(all)
+        );
+        assert_eq!(a.fold_lanes(0.0, |acc, x| acc + x), a.horizontal_add());
+        assert_eq!(a.fold_lanes(1.0, |acc, x| acc - x * 2.0), -21.0);
+    }
+
+    #[test]
+    fn test_broadcast_lane() {
+        let a = Vec4f::new(-1.0, 2.5, 3.0, -4.0);
+        assert_eq!(a.broadcast_lane(0), [-1.0; 4]);
+        assert_eq!(a.broadcast_lane(1), [2.5; 4]);
+        assert_eq!(a.broadcast_lane(2), [3.0; 4]);
+        assert_eq!(a.broadcast_lane(3), [-4.0; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_broadcast_lane_panic() {
+        Vec4f::default().broadcast_lane(4);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        assert_eq!(
+            Vec4f::from_csv("-1.5,2,0.25,-4").unwrap(),
+            [-1.5, 2.0, 0.25, -4.0]
+        );
+        assert_eq!(
+            Vec4f::from_csv(" 1 ,\t2, 3 ,4 ").unwrap(),
+            [1.0, 2.0, 3.0, 4.0]
+        );
+        assert_eq!(Vec4f::from_csv("1,2,3"), Err(ParseVec4fError::WrongLength));
+        assert_eq!(
+            Vec4f::from_csv("1,2,3,4,5"),
+            Err(ParseVec4fError::WrongLength)
+        );
+        assert!(matches!(
+            Vec4f::from_csv("1,x,3,4"),
+            Err(ParseVec4fError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            Vec4f::from_csv(""),
+            Err(ParseVec4fError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            Vec4f::from_csv("1,,3,4"),
+            Err(ParseVec4fError::InvalidValue(_))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_csv_roundtrip() {
+        let a = Vec4f::new(-1.5, 0.1, 1.0 / 3.0, -1234.5678);
+        let csv = a.to_csv();
+        assert_eq!(Vec4f::from_csv(&csv).unwrap(), a);
+        assert_eq!(Vec4f::new(1.0, 2.0, -3.0, 0.5).to_csv(), "1,2,-3,0.5");
+    }
+
+    #[test]
+    fn test_weighted_sum() {
+        let values = [2.0f32, -1.0, 4.0, 0.5];
+        let weights = [1.0f32, 3.0, 0.5, 2.0];
+        let mut expected = 0.0f32;
+        for i in 0..4 {
+            expected += values[i] * weights[i];
+        }
+        let sum = Vec4f::from(values).weighted_sum(Vec4f::from(weights));
+        assert_eq!(sum, expected);
+
+        let total_weight = Vec4f::from(weights).horizontal_add();
+        assert!(f32::abs(sum / total_weight - expected / 6.5) < 0.00001);
+    }
+
+    #[test]
+    fn test_top2() {
+        assert_eq!(Vec4f::new(4.0, 1.0, 3.0, 2.0).top2(), (4.0, 3.0));
+        assert_eq!(Vec4f::new(1.0, 4.0, 2.0, 3.0).top2(), (4.0, 3.0));
+        assert_eq!(Vec4f::new(1.0, 2.0, 3.0, 4.0).top2(), (4.0, 3.0));
+        assert_eq!(Vec4f::new(3.0, 4.0, 1.0, 2.0).top2(), (4.0, 3.0));
+        assert_eq!(Vec4f::new(5.0, 5.0, 1.0, 2.0).top2(), (5.0, 5.0));
+        assert_eq!(Vec4f::new(-1.0, -3.0, -1.0, -2.0).top2(), (-1.0, -1.0));
+        assert_eq!(Vec4f::new(1.0, 3.0, 3.0, 3.0).top2(), (3.0, 3.0));
+        assert_eq!(Vec4f::from_scalar(7.0).top2(), (7.0, 7.0));
+    }
+
+    #[test]
+    fn test_alternating() {
+        assert_eq!(Vec4f::alternating(1.0, -1.0), [1.0, -1.0, 1.0, -1.0]);
+        assert_eq!(Vec4f::alternating(0.5, 2.0), [0.5, 2.0, 0.5, 2.0]);
+        assert_eq!(
+            Vec4f::alternating(3.0, 4.0).swap_pairs(),
+            Vec4f::alternating(4.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn test_is_monotonic() {
+        assert!(Vec4f::new(-1.0, 0.0, 2.0, 5.0).is_monotonic(0.0));
+        assert!(Vec4f::new(-1.0, -1.0, 2.0, 2.0).is_monotonic(0.0));
+        assert!(Vec4f::new(1.0, 0.99, 2.0, 1.995).is_monotonic(0.01));
+        assert!(!Vec4f::new(1.0, 0.99, 2.0, 1.995).is_monotonic(0.0));
+        assert!(!Vec4f::new(1.0, 2.0, 3.0, 2.5).is_monotonic(0.1));
+        assert!(!Vec4f::new(2.0, 1.0, 3.0, 4.0).is_monotonic(0.5));
+        assert!(Vec4f::new(4.0, 3.0, 2.0, 1.0).is_monotonic(1.0));
+        assert!(!Vec4f::new(1.0, f32::NAN, 3.0, 4.0).is_monotonic(1.0));
+    }
+
+    #[test]
+    fn test_aligned_buffer() {
+        let mut buffer = AlignedBuffer4::default();
+        assert!(Vec4f::is_aligned(buffer.as_slice()));
+        assert_eq!(buffer.as_slice().len(), 4);
+
+        let a = Vec4f::new(-1.0, 2.0, -3.0, 4.0);
+        a.store_aligned(buffer.as_mut_slice());
+        assert_eq!(buffer.0, [-1.0, 2.0, -3.0, 4.0]);
+        a.store_aligned_nocache(buffer.as_mut_slice());
+        assert_eq!(buffer, a.to_aligned_array());
+
+        buffer.as_mut_slice()[2] = 10.0;
+        let mut b = Vec4f::default();
+        b.load_aligned(buffer.as_slice());
+        assert_eq!(b, [-1.0, 2.0, 10.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rsqrt_bittrick() {
+        for i in 1..1000 {
+            let x = i as f32 * 0.37;
+            let a = Vec4f::new(x, 1.0 / x, x * 1000.0, x * 0.001);
+            let exact = Vec4f::from_scalar(1.0) / a.sqrt();
+            let error = ((a.rsqrt_bittrick() - exact) / exact).abs();
+            assert!(error.all_in_range(0.0, 0.002));
+        }
+
+        let edges = Vec4f::new(
+            f32::MIN_POSITIVE,
+            f32::MAX,
+            f32::from_bits(1),
+            f32::from_bits(0x0040_0000),
+        );
+        let exact = Vec4f::new(
+            1.0 / (f32::MIN_POSITIVE as f64).sqrt() as f32,
+            1.0 / (f32::MAX as f64).sqrt() as f32,
+            1.0 / (f32::from_bits(1) as f64).sqrt() as f32,
+            1.0 / (f32::from_bits(0x0040_0000) as f64).sqrt() as f32,
+        );
+        let error = ((edges.rsqrt_bittrick() - exact) / exact).abs();
+        assert!(error.all_in_range(0.0, 0.002));
+
+        let zeros = Vec4f::new(0.0, -0.0, f32::INFINITY, 1.0).rsqrt_bittrick();
+        assert!(zeros.bit_eq_array(&[f32::INFINITY, f32::NEG_INFINITY, 0.0, zeros[3]]));
+        let invalid = Vec4f::new(-1.0, f32::NEG_INFINITY, f32::NAN, -f32::from_bits(1));
+        let mut values = [0.0f32; 4];
+        invalid.rsqrt_bittrick().store(&mut values);
+        assert!(values.iter().all(|value| value.is_nan()));
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(Vec4f::new(1.0, 3.0, 6.0, 10.0).diff(), [2.0, 3.0, 4.0, 0.0]);
+        assert_eq!(
+            Vec4f::new(-1.0, 1.0, 0.5, f32::INFINITY).diff(),
+            [2.0, -0.5, f32::INFINITY, 0.0]
+        );
+        assert_eq!(Vec4f::from_scalar(2.0).diff(), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_bit_eq_array() {
+        let a = Vec4f::new(0.0, -0.0, 0.0, 0.0);
+        assert_eq!(a, [0.0; 4]);
+        assert!(!a.bit_eq_array(&[0.0; 4]));
+        assert!(a.bit_eq_array(&[0.0, -0.0, 0.0, 0.0]));
+        assert!(Vec4f::default().bit_eq_array(&[0.0; 4]));
+
+        let b = Vec4f::new(1.0, f32::NAN, -2.0, f32::INFINITY);
+        assert_ne!(b, [1.0, f32::NAN, -2.0, f32::INFINITY]);
+        assert!(b.bit_eq_array(&[1.0, f32::NAN, -2.0, f32::INFINITY]));
+        assert!(!b.bit_eq_array(&[1.0, -f32::NAN, -2.0, f32::INFINITY]));
+    }
+
+    #[test]
+    fn test_sse_mse() {
+        let prediction = [0.5f32, -1.0, 2.25, 3.0];
+        let target = [1.0f32, 1.0, 2.0, -1.0];
+        let mut expected = 0.0f32;
+        for i in 0..4 {
+            expected += (prediction[i] - target[i]) * (prediction[i] - target[i]);
+        }
+        let a = Vec4f::from(prediction);
+        let b = Vec4f::from(target);
+        assert_eq!(a.sse(b), expected);
+        assert_eq!(a.mse(b), expected / 4.0);
+        assert_eq!(a.sse(a), 0.0);
+        assert_eq!(b.sse(a), a.sse(b));
+    }
+
+    #[test]
+    fn test_first_ge() {
+        let a = Vec4f::new(4.0, -1.0, 2.0, 8.0);
+        assert_eq!(a.first_ge(3.0), Some(0));
+        assert_eq!(a.first_ge(4.0), Some(0));
+        assert_eq!(a.first_ge(5.0), Some(3));
+        assert_eq!(a.first_ge(-2.0), Some(0));
+        assert_eq!(a.first_ge(8.5), None);
+        assert_eq!(Vec4f::new(0.0, 1.0, 2.0, 3.0).first_ge(1.5), Some(2));
+        assert_eq!(Vec4f::from_scalar(f32::NAN).first_ge(0.0), None);
+    }
+
+    #[test]
+    fn test_premultiply_alpha() {
+        assert_eq!(
+            Vec4f::new(0.5, 0.5, 0.5, 0.5).premultiply_alpha(),
+            [0.25, 0.25, 0.25, 0.5]
+        );
+        assert_eq!(
+            Vec4f::new(1.0, 0.5, 0.25, 0.0).premultiply_alpha(),
+            [0.0, 0.0, 0.0, 0.0]
+        );
+        let opaque = Vec4f::new(0.2, 0.4, 0.6, 1.0);
+        assert_eq!(opaque.premultiply_alpha(), opaque);
+    }
+
+    #[test]
+    fn test_unpremultiply_alpha() {
+        let color = Vec4f::new(0.5, 0.25, 1.0, 0.5);
+        assert_eq!(
+            color.premultiply_alpha().unpremultiply_alpha(),
+            [0.5, 0.25, 1.0, 0.5]
+        );
+        assert_eq!(
+            Vec4f::new(0.3, 0.2, 0.1, 0.0).unpremultiply_alpha(),
+            [0.0, 0.0, 0.0, 0.0]
+        );
+        let opaque = Vec4f::new(0.2, 0.4, 0.6, 1.0);
+        assert_eq!(opaque.unpremultiply_alpha(), opaque);
+    }
+
+    #[test]
+    fn test_luminance() {
+        let (r, g, b) = (0.8f32, 0.3f32, 0.5f32);
+        let expected = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let color = Vec4f::new(r, g, b, 0.25);
+        assert!(f32::abs(color.luminance() - expected) < 0.00001);
+        assert_eq!(Vec4f::new(0.0, 0.0, 0.0, 1.0).luminance(), 0.0);
+        assert!(f32::abs(Vec4f::new(0.0, 1.0, 0.0, 0.0).luminance() - 0.7152) < 0.00001);
+    }
+
+    #[test]
+    fn test_scatter_add() {
+        let mut bins = [0.0f32; 4];
+        Vec4f::new(1.0, 2.0, 3.0, 4.0).scatter_add(&mut bins, [3, 2, 1, 0]);
+        assert_eq!(bins, [4.0, 3.0, 2.0, 1.0]);
+
+        let mut bins = [0.0f32; 4];
+        Vec4f::from_scalar(1.0).scatter_add(&mut bins, [1, 1, 1, 1]);
+        Vec4f::new(0.5, -1.0, 2.0, 0.25).scatter_add(&mut bins, [0, 1, 0, 3]);
+        assert_eq!(bins, [2.5, 3.0, 0.0, 0.25]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_scatter_add_panic() {
+        let mut bins = [0.0f32; 4];
+        Vec4f::from_scalar(1.0).scatter_add(&mut bins, [0, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_hold_where() {
+        let mut state = Vec4f::default();
+        let step = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        for _ in 0..3 {
+            let next = state + step;
+            let (_, overflow) =
+                next.clamp_reported(Vec4f::from_scalar(-5.0), Vec4f::from_scalar(5.0));
+            state = next.hold_where(state, overflow);
+        }
+        assert_eq!(state, [3.0, 4.0, 3.0, 4.0]);
+
+        let a = Vec4f::new(5.0, 6.0, 7.0, 8.0);
+        let b = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        let mask = Vec4fb::new(true, false, false, true);
+        assert_eq!(a.hold_where(b, mask), [1.0, 6.0, 7.0, 4.0]);
+        assert_eq!(a.hold_where(b, !mask), [5.0, 2.0, 3.0, 8.0]);
+    }
+
+    #[test]
+    fn test_biquad_step() {
+        let filters = [
+            // low-pass
+            (0.2f32, 0.4f32, 0.2f32, -0.6f32, 0.2f32),
+            // high-pass
+            (0.5, -1.0, 0.5, -0.3, 0.1),
+            // one-pole low-pass with unity b0
+            (1.0, 0.0, 0.0, -0.5, 0.0),
+        ];
+        for (b0, b1, b2, a1, a2) in filters {
+            // b0 is applied by scaling the input, so the state holds scaled inputs
+            let coeffs = Vec4f::new(b1 / b0, b2 / b0, a1, a2);
+            let mut state = [0.0f32; 4];
+
+            let (mut x1, mut x2, mut y1, mut y2) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+            for n in 0..16 {
+                let x = if n == 0 { 1.0 } else { 0.5 };
+                let y = b0 * x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+                x2 = x1;
+                x1 = x;
+                y2 = y1;
+                y1 = y;
+
+                let output = Vec4f::biquad_step(&mut state, b0 * x, coeffs);
+                assert!(output.is_finite());
+                assert!(f32::abs(output - y) < 0.00001);
+                assert_eq!(state[..2], [b0 * x1, b0 * x2]);
+                compare_approx_vec4f(&Vec4f::from(state), [b0 * x1, b0 * x2, y1, y2]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier() {
+        let (p0, p1, p2, p3) = (1.0f32, -2.0f32, 4.0f32, 0.5f32);
+        let bezier = |t: f32| {
+            let u = 1.0 - t;
+            u * u * u * p0 + 3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t * p3
+        };
+        let t = Vec4f::new(0.0, 1.0, 0.5, 0.3);
+        let curve = Vec4f::cubic_bezier(p0, p1, p2, p3, t);
+        assert_eq!(curve[0], p0);
+        assert_eq!(curve[1], p3);
+        compare_approx_vec4f(&curve, [p0, p3, bezier(0.5), bezier(0.3)]);
+    }
+
+    #[test]
+    fn test_catmull_rom() {
+        let (p0, p1, p2, p3) = (0.5f32, 2.0f32, -1.0f32, 3.0f32);
+        let catmull_rom = |t: f32| {
+            let t2 = t * t;
+            let t3 = t2 * t;
+            0.5 * (2.0 * p1
+                + (-p0 + p2) * t
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t3)
+        };
+        let t = Vec4f::new(0.0, 1.0, 0.5, 0.8);
+        let curve = Vec4f::catmull_rom(p0, p1, p2, p3, t);
+        assert_eq!(curve[0], p1);
+        compare_approx_vec4f(&curve, [p1, p2, catmull_rom(0.5), catmull_rom(0.8)]);
+    }
+
+    #[test]
+    fn test_denoise() {
+        let a = Vec4f::new(0.0999, -0.0999, 0.1001, -0.1001);
+        assert_eq!(a.denoise(0.1), [0.0, 0.0, 0.1001, -0.1001]);
+        assert!(a.denoise(0.1).bit_eq_array(&[0.0, 0.0, 0.1001, -0.1001]));
+        assert_eq!(a.denoise(0.0), a);
+        assert_eq!(a.denoise(1.0), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_soft_threshold() {
+        let a = Vec4f::new(1.5, -1.5, 0.25, -0.75);
+        assert_eq!(a.soft_threshold(0.5), [1.0, -1.0, 0.0, -0.25]);
+        assert_eq!(a.soft_threshold(1.0), [0.5, -0.5, 0.0, 0.0]);
+        assert_eq!(a.soft_threshold(2.0), [0.0; 4]);
+        assert_eq!(a.soft_threshold(0.0), a);
+    }
+
+    #[test]
+    fn test_decay_max() {
+        let inputs = [
+            Vec4f::new(8.0, 0.0, 1.0, 0.0),
+            Vec4f::new(0.0, 0.0, 0.0, 2.0),
+            Vec4f::new(0.0, 4.0, 0.0, 0.0),
+            Vec4f::new(3.0, 0.0, 0.0, 0.0),
+        ];
+        let expected = [
+            [8.0, 0.0, 1.0, 0.0],
+            [4.0, 0.0, 0.5, 2.0],
+            [2.0, 4.0, 0.25, 1.0],
+            [3.0, 2.0, 0.125, 0.5],
+        ];
+        let mut envelope = Vec4f::default();
+        for (input, exp) in inputs.iter().zip(expected.iter()) {
+            envelope = input.decay_max(envelope, 0.5);
+            assert_eq!(envelope, *exp);
+        }
+    }
+
+    #[test]
+    fn test_clamp_symmetric() {
+        let a = Vec4f::new(-10.0, 2.5, 0.0, 10.0);
+        assert_eq!(a.clamp_symmetric(2.0), [-2.0, 2.0, 0.0, 2.0]);
+        assert_eq!(a.clamp_symmetric(5.0), [-5.0, 2.5, 0.0, 5.0]);
+        assert_eq!(a.clamp_symmetric(20.0), a);
+    }
+
+    #[test]
+    fn test_clip_by_norm() {
+        let mut data = [
+            Vec4f::new(3.0, -3.0, 3.0, -3.0),
+            Vec4f::new(0.0, 0.0, 0.0, 0.0),
+        ];
+        Vec4f::clip_by_norm(&mut data, 2.0);
+        compare_approx_vec4f(&data[0], [1.0, -1.0, 1.0, -1.0]);
+        assert_eq!(data[1], [0.0; 4]);
+
+        let original = [
+            Vec4f::new(1.0, -2.0, 0.0, 2.0),
+            Vec4f::new(0.0, 0.0, 0.0, 0.0),
+        ];
+        let mut data = original;
+        Vec4f::clip_by_norm(&mut data, 3.0);
+        assert_eq!(data, original);
+        Vec4f::clip_by_norm(&mut data, 10.0);
+        assert_eq!(data, original);
+
+        let mut empty: [Vec4f; 0] = [];
+        Vec4f::clip_by_norm(&mut empty, 1.0);
+    }
+
+    #[test]
+    fn test_scale_by_diagonal() {
+        let a = Vec4f::new(1.5, -2.0, 3.25, 8.0);
+        let diagonal = Vec4f::new(-4.0, 0.25, 1.0, 3.0);
+        assert_eq!(a.scale_by_diagonal(diagonal), a * diagonal);
+        assert_eq!(a.scale_by_diagonal(Vec4f::from_scalar(1.0)), a);
+    }
+
+    #[test]
+    fn test_rotate2d() {
+        let points = Vec4f::new(3.0, 1.0, -2.0, 5.0);
+        assert_eq!(points.rotate2d(0.0, 1.0), [-1.0, 3.0, -5.0, -2.0]);
+        assert_eq!(points.rotate2d(0.0, -1.0), [1.0, -3.0, 5.0, 2.0]);
+        assert_eq!(points.rotate2d(1.0, 0.0), points);
+        let half = core::f32::consts::FRAC_1_SQRT_2;
+        let rotated = Vec4f::new(1.0, 0.0, 1.0, 1.0).rotate2d(half, half);
+        compare_approx_vec4f(&rotated, [half, half, 0.0, 2.0 * half]);
+    }
+
+    #[test]
+    fn test_has_overflow() {
+        let a = Vec4f::new(1.0, -f32::MAX, f32::NAN, 0.0);
+        assert_eq!(a.is_infinite(), [false; 4]);
+        assert!(!a.has_overflow());
+        let b = Vec4f::new(1.0, 2.0, f32::NEG_INFINITY, 0.0);
+        assert_eq!(b.is_infinite(), [false, false, true, false]);
+        assert!(b.has_overflow());
+        assert!(Vec4f::new(f32::INFINITY, 0.0, 0.0, 0.0).has_overflow());
+    }
+
+    #[test]
+    fn test_phase_accumulate() {
+        let mut phase = Vec4f::default();
+        let increment = Vec4f::new(0.25, 0.5, -0.25, -0.75);
+        let expected = [
+            [0.25, 0.5, 0.75, 0.25],
+            [0.5, 0.0, 0.5, 0.5],
+            [0.75, 0.5, 0.25, 0.75],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+        for exp in expected.iter() {
+            phase = phase.phase_accumulate(increment);
+            assert_eq!(phase, *exp);
+        }
+
+        let wrapped =
+            Vec4f::new(0.5, 0.0, 0.0, 0.0).phase_accumulate(Vec4f::new(3.0, -2.5, 1.0, -1e-9));
+        assert_eq!(wrapped, [0.5, 0.5, 0.0, 0.0]);
+
+        let exact =
+            Vec4f::new(0.25, 0.5, 0.75, 0.0).phase_accumulate(Vec4f::new(-1.25, -2.5, -0.75, -3.0));
+        let mut values = [1.0f32; 4];
+        exact.store(&mut values);
+        assert_eq!(values.map(f32::to_bits), [0; 4]);
+    }
+
+    #[test]
+    fn test_vec2d() {
+        assert_eq!(Vec2d::LEN, 2);
+        assert_eq!(core::mem::size_of::<Vec2d>(), 16);
+        let a = Vec2d::new(1.5, -4.0);
+        let b = Vec2d::from_scalar(2.0);
+        assert_eq!(a + b, [3.5, -2.0]);
+        assert_eq!(a - b, [-0.5, -6.0]);
+        assert_eq!(a * b, [3.0, -8.0]);
+        assert_eq!(a / b, [0.75, -2.0]);
+        assert_eq!(Vec2d::max(a, b), [2.0, 2.0]);
+        assert_eq!(Vec2d::min(a, b), [1.5, -4.0]);
+        assert_eq!(Vec2d::new(9.0, 0.25).sqrt(), [3.0, 0.5]);
+        assert_ne!(a, b);
+
+        let mut buffer = Align16([0.0f64; 3]);
+        a.store_aligned(&mut buffer.0);
+        assert_eq!(buffer.0, [1.5, -4.0, 0.0]);
+        a.store(&mut buffer.0[1..]);
+        assert_eq!(buffer.0, [1.5, 1.5, -4.0]);
+        let mut c = Vec2d::default();
+        c.load(&buffer.0[1..]);
+        assert_eq!(c, a);
+        c.load_aligned(&buffer.0);
+        assert_eq!(c, [1.5, 1.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec2d_load_aligned_panic() {
+        let buffer = Align16([0.0f64; 3]);
+        Vec2d::default().load_aligned(&buffer.0[1..]);
+    }
+
+    #[test]
+    fn test_vec2d_horizontal_add() {
+        assert_eq!(Vec2d::new(1.5, 2.25).horizontal_add(), 3.75);
+        assert_eq!(Vec2d::new(1e300, -1e300).horizontal_add(), 0.0);
+        assert_eq!(
+            Vec2d::new(-0.0, -0.0).horizontal_add().to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert!(Vec2d::new(f64::NAN, 1.0).horizontal_add().is_nan());
+        assert_eq!(
+            Vec2d::new(f64::INFINITY, 1.0).horizontal_add(),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_vec2d_abs() {
+        let a = Vec2d::new(-0.0, 0.0).abs();
+        let mut values = [1.0f64; 2];
+        a.store(&mut values);
+        assert_eq!(values[0].to_bits(), 0);
+        assert_eq!(values[1].to_bits(), 0);
+        assert_eq!(Vec2d::new(-2.5, 3.0).abs(), [2.5, 3.0]);
+        assert_eq!(
+            Vec2d::new(f64::NEG_INFINITY, -f64::MIN_POSITIVE).abs(),
+            [f64::INFINITY, f64::MIN_POSITIVE]
+        );
+    }
+
+    #[test]
+    fn test_centroid4() {
+        let a = Vec4f::new(1.0, -2.0, 10.0, 0.5);
+        let b = Vec4f::new(3.0, -2.0, -10.0, 0.5);
+        let c = Vec4f::new(5.0, 6.0, 2.0, 0.5);
+        let d = Vec4f::new(7.0, 6.0, 0.0, 0.5);
+        assert_eq!(Vec4f::centroid4(a, b, c, d), [4.0, 2.0, 0.5, 0.5]);
+        assert_eq!(Vec4f::centroid4(a, a, a, a), a);
+    }
+
+    #[test]
+    fn test_butterfly() {
+        // 4-point DFT of [1, 2, 3, 4] done as two radix-2 stages
+        let (even_sum, even_difference) = Vec4f::butterfly(
+            Vec4f::new(1.0, 0.0, 2.0, 0.0),
+            Vec4f::new(3.0, 0.0, 4.0, 0.0),
+            Vec4f::new(1.0, 0.0, 1.0, 0.0),
+        );
+        assert_eq!(even_sum, [4.0, 0.0, 6.0, 0.0]);
+        assert_eq!(even_difference, [-2.0, 0.0, -2.0, 0.0]);
+
+        let a = Vec4f::new(
+            even_sum[0],
+            even_sum[1],
+            even_difference[0],
+            even_difference[1],
+        );
+        let b = Vec4f::new(
+            even_sum[2],
+            even_sum[3],
+            even_difference[2],
+            even_difference[3],
+        );
+        let (low, high) = Vec4f::butterfly(a, b, Vec4f::new(1.0, 0.0, 0.0, -1.0));
+        assert_eq!(low, [10.0, 0.0, -2.0, 2.0]);
+        assert_eq!(high, [-2.0, 0.0, -2.0, -2.0]);
+
+        let (sum, difference) = Vec4f::butterfly(
+            Vec4f::default(),
+            Vec4f::new(1.0, 2.0, -3.0, 0.5),
+            Vec4f::new(0.0, 1.0, 2.0, -1.0),
+        );
+        assert_eq!(sum, [-2.0, 1.0, -5.5, 4.0]);
+        assert_eq!(difference, [2.0, -1.0, 5.5, -4.0]);
+    }
+
+    #[test]
+    fn test_vec4i() {
+        assert_eq!(Vec4i::LEN, 4);
+        assert_eq!(core::mem::size_of::<Vec4i>(), 16);
+        let a = Vec4i::new(7, -3, 0, i32::MAX);
+        let b = Vec4i::from_scalar(2);
+        assert_eq!(a + b, [9, -1, 2, i32::MIN + 1]);
+        assert_eq!(a - b, [5, -5, -2, i32::MAX - 2]);
+        assert_eq!(a & b, [2, 0, 0, 2]);
+        assert_eq!(a | b, [7, -1, 2, i32::MAX]);
+        assert_eq!(a ^ b, [5, -1, 2, i32::MAX - 2]);
+        assert_eq!(!Vec4i::default(), [-1; 4]);
+        assert_ne!(a, b);
+
+        let mut buffer = [0i32; 5];
+        a.store(&mut buffer[1..]);
+        assert_eq!(buffer, [0, 7, -3, 0, i32::MAX]);
+        let mut c = Vec4i::default();
+        c.load(&buffer[1..]);
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_vec4i_mul() {
+        let a = Vec4i::new(-3, -7, 46341, i32::MIN);
+        let b = Vec4i::new(5, -9, 46341, -1);
+        assert_eq!(
+            a * b,
+            [
+                -15,
+                63,
+                46341i32.wrapping_mul(46341),
+                i32::MIN.wrapping_mul(-1)
+            ]
+        );
+        let c = Vec4i::new(-1, 123456, -65536, 0x7FFF_0001);
+        let d = Vec4i::new(-1, -654321, 65537, -3);
+        assert_eq!(
+            c * d,
+            [
+                1,
+                123456i32.wrapping_mul(-654321),
+                (-65536i32).wrapping_mul(65537),
+                0x7FFF_0001i32.wrapping_mul(-3)
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec4i_load_panic() {
+        Vec4i::default().load(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_sign_changes() {
+        assert_eq!(Vec4f::new(1.0, -1.0, 1.0, -1.0).sign_changes(), 3);
+        assert_eq!(Vec4f::new(1.0, 2.0, 3.0, 4.0).sign_changes(), 0);
+        assert_eq!(Vec4f::new(-1.0, -2.0, -3.0, -4.0).sign_changes(), 0);
+        assert_eq!(Vec4f::new(-1.0, -2.0, 3.0, 4.0).sign_changes(), 1);
+        assert_eq!(Vec4f::new(1.0, 2.0, 3.0, -4.0).sign_changes(), 1);
+        assert_eq!(Vec4f::new(0.0, -0.0, 0.0, 0.0).sign_changes(), 2);
+    }
+
+    #[test]
+    fn test_round_to_int() {
+        let a = Vec4f::new(-2.5, -1.5, 0.5, 1.5);
+        assert_eq!(a.round_to_int(), [-2, -2, 0, 2]);
+        assert_eq!(a.truncate_to_int(), [-2, -1, 0, 1]);
+        let b = Vec4f::new(-3.7, 3.7, -0.2, 1e6);
+        assert_eq!(b.round_to_int(), [-4, 4, 0, 1000000]);
+        assert_eq!(b.truncate_to_int(), [-3, 3, 0, 1000000]);
+
+        let c = Vec4f::new(3e9, -3e9, f32::NAN, f32::INFINITY);
+        assert_eq!(c.round_to_int(), [i32::MIN; 4]);
+        assert_eq!(c.truncate_to_int(), [i32::MIN; 4]);
+        // 2147483648.0 is the first f32 value above i32::MAX
+        let d = Vec4f::new(2147483648.0, -2147483648.0, 2147483520.0, 0.0);
+        assert_eq!(d.round_to_int(), [i32::MIN, i32::MIN, 2147483520, 0]);
+        assert_eq!(d.truncate_to_int(), [i32::MIN, i32::MIN, 2147483520, 0]);
+
+        let e = Vec4i::new(-7, 0, 123, i32::MIN);
+        assert_eq!(e.to_f32(), [-7.0, 0.0, 123.0, -2147483648.0]);
+        assert_eq!(b.truncate_to_int().to_f32(), b.truncate());
+    }
+
+    #[test]
+    fn test_crossfade() {
+        let a = Vec4f::new(1.0, -2.0, 0.5, 8.0);
+        let b = Vec4f::new(3.0, 2.0, -0.5, 0.0);
+        assert_eq!(Vec4f::crossfade(a, b, 0.0), a);
+        assert_eq!(Vec4f::crossfade(a, b, 1.0), b);
+        assert_eq!(Vec4f::crossfade(a, b, 0.5), [2.0, 0.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cmp_mask() {
+        let a = Vec4f::new(1.0, 2.0, f32::NAN, -0.0);
+        let b = Vec4f::new(2.0, 2.0, 2.0, 0.0);
+        let ones = Vec4f::from_scalar(1.0);
+        assert_eq!(a.cmp_lt(b) & ones, [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(a.cmp_le(b) & ones, [1.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a.cmp_gt(b) & ones, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(a.cmp_ge(b) & ones, [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a.cmp_eq(b) & ones, [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a.cmp_ne(b) & ones, [1.0, 0.0, 1.0, 0.0]);
+
+        let mut values = [0.0f32; 4];
+        a.cmp_le(b).store(&mut values);
+        assert_eq!(values.map(f32::to_bits), [u32::MAX, u32::MAX, 0, u32::MAX]);
+
+        let between = a.cmp_ge(Vec4f::from_scalar(0.0)) & a.cmp_lt(Vec4f::from_scalar(1.5));
+        assert_eq!(between & ones, [1.0, 0.0, 0.0, 1.0]);
+        let outside = a.cmp_lt(Vec4f::from_scalar(0.0)) | a.cmp_gt(Vec4f::from_scalar(1.5));
+        assert_eq!(outside & ones, [0.0, 1.0, 0.0, 0.0]);
+        assert_eq!((a.cmp_eq(b) ^ a.cmp_ne(b)) & ones, [1.0; 4]);
+    }
+
+    #[test]
+    fn test_select() {
+        let a = Vec4f::new(1.0, -2.0, f32::NAN, 4.0);
+        let b = Vec4f::new(0.0, 0.0, 0.0, 8.0);
+        assert_eq!(Vec4f::select(a.cmp_gt(b), a, b), [1.0, 0.0, 0.0, 8.0]);
+        assert_eq!(Vec4f::select(a.cmp_lt(b), a, b), [0.0, -2.0, 0.0, 4.0]);
+        assert_eq!(Vec4f::select(a.cmp_ge(b), a, b), [1.0, 0.0, 0.0, 8.0]);
+        let selected = Vec4f::select(a.cmp_ne(b), a, b);
+        assert!(selected[2].is_nan());
+        assert_eq!(selected.insert(2, 0.0), [1.0, -2.0, 0.0, 4.0]);
+
+        let all_ones = Vec4f::from_scalar(f32::from_bits(u32::MAX));
+        let all_zeros = Vec4f::default();
+        assert_eq!(Vec4f::select(all_ones, b, a), b);
+        assert!(Vec4f::select(all_zeros, b, a)[2].is_nan());
+        let mixed = Vec4f::new(f32::from_bits(u32::MAX), 0.0, f32::from_bits(u32::MAX), 0.0);
+        assert_eq!(Vec4f::select(mixed, b, a), [0.0, -2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_apply_stereo_gain() {
+        let frames = Vec4f::new(0.5, -0.25, 1.0, 2.0);
+        assert_eq!(frames.apply_stereo_gain(2.0, 0.0), [1.0, 0.0, 2.0, 0.0]);
+        assert_eq!(frames.apply_stereo_gain(0.0, -4.0), [0.0, 1.0, 0.0, -8.0]);
+        assert_eq!(frames.apply_stereo_gain(1.0, 1.0), frames);
+    }
+
+    #[test]
+    fn test_one_pole() {
+        let target = Vec4f::new(1.0, -8.0, 0.0, 100.0);
+        let mut state = Vec4f::new(0.0, 0.0, 16.0, 100.0);
+        state = target.one_pole(state, 0.5);
+        assert_eq!(state, [0.5, -4.0, 8.0, 100.0]);
+        state = target.one_pole(state, 0.5);
+        assert_eq!(state, [0.75, -6.0, 4.0, 100.0]);
+        for _ in 0..100 {
+            state = target.one_pole(state, 0.25);
+        }
+        compare_approx_vec4f(&state, [1.0, -8.0, 0.0, 100.0]);
+        assert_eq!(target.one_pole(state, 1.0), target);
+        assert_eq!(target.one_pole(state, 0.0), state);
+    }
+
+    #[test]
+    fn test_floor_ceil() {
+        let a = Vec4f::new(-1.5, -0.25, 0.25, 2.75);
+        assert_eq!(a.floor(), [-2.0, -1.0, 0.0, 2.0]);
+        assert_eq!(a.ceil(), [-1.0, 0.0, 1.0, 3.0]);
+
+        let integers = Vec4f::new(-3.0, -1.0, 0.0, 5.0);
+        assert_eq!(integers.floor(), integers);
+        assert_eq!(integers.ceil(), integers);
+
+        let large = Vec4f::new(8388609.0, -8388609.0, 3e9, -1e30);
+        assert_eq!(large.floor(), large);
+        assert_eq!(large.ceil(), large);
+        let edge = Vec4f::new(8388607.5, -8388607.5, f32::INFINITY, f32::NEG_INFINITY);
+        assert_eq!(
+            edge.floor(),
+            [8388607.0, -8388608.0, f32::INFINITY, f32::NEG_INFINITY]
+        );
+        assert_eq!(
+            edge.ceil(),
+            [8388608.0, -8388607.0, f32::INFINITY, f32::NEG_INFINITY]
+        );
+
+        let nan = Vec4f::new(f32::NAN, -0.0, -0.5, 0.0);
+        assert!(nan.floor()[0].is_nan());
+        assert!(nan.ceil()[0].is_nan());
+        let mut values = [0.0f32; 4];
+        nan.ceil().store(&mut values);
+        assert_eq!(values[1].to_bits(), (-0.0f32).to_bits());
+        assert_eq!(values[2].to_bits(), (-0.0f32).to_bits());
+        assert_eq!(values[3].to_bits(), 0);
+        nan.floor().store(&mut values);
+        assert_eq!(values[1].to_bits(), (-0.0f32).to_bits());
+        assert_eq!(values[2], -1.0);
+    }
+
+    #[test]
+    fn test_snap_to_levels() {
+        let a = Vec4f::new(0.2, 0.49, 0.51, 0.9);
+        assert_eq!(a.snap_to_levels(2), [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(
+            Vec4f::new(-3.0, 0.0, 1.0, 7.0).snap_to_levels(2),
+            [0.0, 0.0, 1.0, 1.0]
+        );
+        compare_approx_vec4f(&a.snap_to_levels(4), [1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+        let b = Vec4f::new(0.1, 0.2, 0.8, -0.1);
+        compare_approx_vec4f(&b.snap_to_levels(4), [0.0, 1.0 / 3.0, 2.0 / 3.0, 0.0]);
+        assert_eq!(
+            Vec4f::new(0.0, 0.25, 0.5, 1.0).snap_to_levels(5),
+            [0.0, 0.25, 0.5, 1.0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_snap_to_levels_panic() {
+        Vec4f::from_scalar(0.5).snap_to_levels(1);
+    }
+
+    #[test]
+    fn test_horizontal_min_max() {
+        let a = Vec4f::new(3.0, -1.0, 7.0, 2.0);
+        assert_eq!(a.horizontal_max(), 7.0);
+        assert_eq!(a.horizontal_min(), -1.0);
+        let duplicates = Vec4f::new(5.0, -2.0, 5.0, -2.0);
+        assert_eq!(duplicates.horizontal_max(), 5.0);
+        assert_eq!(duplicates.horizontal_min(), -2.0);
+        assert_eq!(Vec4f::from_scalar(1.5).horizontal_max(), 1.5);
+        assert_eq!(Vec4f::from_scalar(1.5).horizontal_min(), 1.5);
+        let infinite = Vec4f::new(f32::NEG_INFINITY, 0.0, f32::INFINITY, 1.0);
+        assert_eq!(infinite.horizontal_max(), f32::INFINITY);
+        assert_eq!(infinite.horizontal_min(), f32::NEG_INFINITY);
+
+        // NaN in the first two lanes is ignored
+        assert_eq!(a.insert(0, f32::NAN).horizontal_max(), 7.0);
+        assert_eq!(a.insert(0, f32::NAN).horizontal_min(), -1.0);
+        assert_eq!(a.insert(1, f32::NAN).horizontal_max(), 7.0);
+        assert_eq!(a.insert(1, f32::NAN).horizontal_min(), 2.0);
+        // NaN in the third lane discards the first lane as well
+        assert_eq!(a.insert(2, f32::NAN).horizontal_max(), 2.0);
+        assert_eq!(a.insert(2, f32::NAN).horizontal_min(), -1.0);
+        // NaN in the last lane is propagated
+        assert!(a.insert(3, f32::NAN).horizontal_max().is_nan());
+        assert!(a.insert(3, f32::NAN).horizontal_min().is_nan());
+    }
+
+    #[test]
+    fn test_horizontal_add_recip() {
+        assert_eq!(Vec4f::from_scalar(1.0).horizontal_add_recip(), 0.25);
+        assert_eq!(
+            Vec4f::new(-1.0, -1.0, 0.5, -0.5).horizontal_add_recip(),
+            -0.5
+        );
+        assert_eq!(
+            Vec4f::new(1.0, -1.0, 2.0, -2.0).horizontal_add_recip(),
+            f32::INFINITY
+        );
+        assert_eq!(Vec4f::default().horizontal_add_recip(), f32::INFINITY);
+        assert_eq!(
+            Vec4f::from_scalar(-0.0).horizontal_add_recip(),
+            f32::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_quantize() {
+        let scale = 1024.0f32;
+        let a = Vec4f::new(0.123, -0.75, 0.9999, -0.0004);
+        let fixed = a.quantize(scale);
+        assert_eq!(fixed, [126, -768, 1024, 0]);
+        let restored = fixed.dequantize(scale);
+        for i in 0..4 {
+            assert!(f32::abs(restored[i] - a[i]) <= 0.5 / scale);
+        }
+        let exact = Vec4f::new(0.5, -0.25, 3.0, 0.0);
+        assert_eq!(exact.quantize(scale).dequantize(scale), exact);
+        assert_eq!(Vec4f::from_scalar(1e10).quantize(scale), [i32::MIN; 4]);
+    }
+
+    #[test]
+    fn test_exponent_mantissa() {
+        let a = Vec4f::new(1.0, 2.0, 0.5, 8.0);
+        assert_eq!(a.exponent(), [0, 1, -1, 3]);
+        assert_eq!(a.mantissa(), [1.0; 4]);
+
+        let b = Vec4f::new(-6.0, 0.1, f32::MAX, f32::MIN_POSITIVE);
+        assert_eq!(b.exponent(), [2, -4, 127, -126]);
+        assert_eq!(b.mantissa()[0], -1.5);
+        assert_eq!(b.mantissa()[1], 1.6);
+        assert_eq!(b.mantissa()[3], 1.0);
+
+        let subnormal = Vec4f::new(
+            f32::from_bits(1),
+            f32::from_bits(0x0040_0000),
+            -f32::from_bits(0x0060_0000),
+            f32::MIN_POSITIVE / 2.0,
+        );
+        assert_eq!(subnormal.exponent(), [-149, -127, -127, -127]);
+        assert_eq!(subnormal.mantissa(), [1.0, 1.0, -1.5, 1.0]);
+
+        let special = Vec4f::new(0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY);
+        assert_eq!(special.exponent(), [i32::MIN, i32::MIN, i32::MAX, i32::MAX]);
+        let mut values = [1.0f32; 4];
+        special.mantissa().store(&mut values);
+        assert_eq!(
+            values.map(f32::to_bits),
+            [
+                0,
+                (-0.0f32).to_bits(),
+                f32::INFINITY.to_bits(),
+                f32::NEG_INFINITY.to_bits()
+            ]
+        );
+        let nan = Vec4f::new(f32::NAN, -f32::NAN, 1.0, 1.0);
+        assert_eq!(nan.exponent(), [i32::MIN, i32::MIN, 0, 0]);
+        assert!(nan.mantissa()[0].is_nan());
+        assert!(nan.mantissa()[1].is_nan());
+    }
+
+    #[test]
+    fn test_pow_lanes() {
+        let a = Vec4f::new(1.5, -2.0, 4.0, 123.0);
+        assert_eq!(
+            a.pow_lanes(Vec4i::new(2, 3, -1, 0)),
+            [2.25, -8.0, 0.25, 1.0]
+        );
+        assert_eq!(a.pow_lanes(Vec4i::from_scalar(0)), [1.0; 4]);
+        for n in (-6..=6).chain([i32::MIN, i32::MIN + 1, i32::MAX]) {
+            let mut expected = [0.0f32; 4];
+            a.pow(n).store(&mut expected);
+            assert!(a.pow_lanes(Vec4i::from_scalar(n)).bit_eq_array(&expected));
+        }
+        let b = Vec4f::new(2.0, -2.0, 0.0, 0.5);
+        assert_eq!(
+            b.pow_lanes(Vec4i::new(10, -3, -2, 7)),
+            [1024.0, -0.125, f32::INFINITY, 0.0078125]
+        );
+        let extreme =
+            Vec4f::new(1.0, -1.0, 2.0, 2.0).pow_lanes(Vec4i::new(i32::MAX, i32::MIN, 200, -200));
+        assert!(extreme[1].is_nan());
+        assert_eq!(extreme.insert(1, 0.0), [1.0, 0.0, f32::INFINITY, 0.0]);
+    }
 }
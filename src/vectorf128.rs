@@ -4,6 +4,7 @@
 //! least `SSE2` instruction set
 
 use crate::intrinsics::*;
+use crate::Vec4i;
 
 use core::option::Option;
 
@@ -20,16 +21,100 @@ fn selectf(s: __m128, a: __m128, b: __m128) -> __m128 {
     }
 }
 
+fn horizontal_maxf(a: __m128) -> f32 {
+    // SAFETY: sse
+    unsafe {
+        let t1: __m128 = _mm_max_ps(a, _mm_movehl_ps(a, a));
+        let t2: __m128 = _mm_max_ps(t1, _mm_shuffle_ps(t1, t1, 1));
+        _mm_cvtss_f32(t2)
+    }
+}
+
+fn horizontal_minf(a: __m128) -> f32 {
+    // SAFETY: sse
+    unsafe {
+        let t1: __m128 = _mm_min_ps(a, _mm_movehl_ps(a, a));
+        let t2: __m128 = _mm_min_ps(t1, _mm_shuffle_ps(t1, t1, 1));
+        _mm_cvtss_f32(t2)
+    }
+}
+
+// Moves every value one lane down and fills the last lane with zero, i.e. [x1, x2, x3, 0]
+fn shift_down(a: __m128) -> __m128 {
+    // SAFETY: sse2
+    unsafe { _mm_castsi128_ps(_mm_srli_si128(_mm_castps_si128(a), 4)) }
+}
+
+// Multiplies two pairs of interleaved complex numbers [re0, im0, re1, im1]
+fn complex_mul(a: __m128, b: __m128) -> __m128 {
+    // SAFETY: sse
+    unsafe {
+        let re: __m128 = _mm_shuffle_ps(a, a, 0xA0);
+        let im: __m128 = _mm_shuffle_ps(a, a, 0xF5);
+        let swapped: __m128 = _mm_shuffle_ps(b, b, 0xB1);
+        let cross: __m128 = _mm_mul_ps(_mm_mul_ps(im, swapped), _mm_setr_ps(-1.0, 1.0, -1.0, 1.0));
+        _mm_add_ps(_mm_mul_ps(re, b), cross)
+    }
+}
+
+// Returns bits of `a` where subnormal values are multiplied by 2^23 to become normal, and the
+// number of the bits they were shifted by (23 for subnormal values, 0 otherwise)
+fn normalized_bits(a: __m128) -> (__m128i, __m128i) {
+    // SAFETY: sse2
+    unsafe {
+        let bits: __m128i = _mm_castps_si128(a);
+        let magnitude: __m128i = _mm_and_si128(bits, _mm_set1_epi32(0x7FFF_FFFF));
+        let subnormal: __m128i = _mm_andnot_si128(
+            _mm_cmpeq_epi32(magnitude, _mm_setzero_si128()),
+            _mm_cmplt_epi32(magnitude, _mm_set1_epi32(0x0080_0000)),
+        );
+        let scaled: __m128i = _mm_castps_si128(_mm_mul_ps(a, _mm_set1_ps(8388608.0)));
+        (
+            _mm_or_si128(
+                _mm_and_si128(subnormal, scaled),
+                _mm_andnot_si128(subnormal, bits),
+            ),
+            _mm_and_si128(subnormal, _mm_set1_epi32(23)),
+        )
+    }
+}
+
+// Same sequence as _MM_TRANSPOSE4_PS, rows become columns
+fn transpose4(r0: __m128, r1: __m128, r2: __m128, r3: __m128) -> [__m128; 4] {
+    // SAFETY: sse
+    unsafe {
+        let t0: __m128 = _mm_unpacklo_ps(r0, r1);
+        let t1: __m128 = _mm_unpacklo_ps(r2, r3);
+        let t2: __m128 = _mm_unpackhi_ps(r0, r1);
+        let t3: __m128 = _mm_unpackhi_ps(r2, r3);
+        [
+            _mm_movelh_ps(t0, t1),
+            _mm_movehl_ps(t1, t0),
+            _mm_movelh_ps(t2, t3),
+            _mm_movehl_ps(t3, t2),
+        ]
+    }
+}
+
 /// Packed array of four `f32` values that can be used for SIMD operations
 #[derive(Clone, Copy)]
 pub struct Vec4f {
-    xmm: __m128,
+    pub(crate) xmm: __m128,
 }
 
 impl Vec4f {
     /// Associated const - size of the packed vector
     pub const LEN: usize = 4;
 
+    /// Associated const - size of the packed vector in bytes
+    pub const BYTES: usize = 16;
+
+    /// Associated const - alignment in bytes required by aligned loads and stores
+    pub const ALIGN: usize = 16;
+
+    /// Associated const - size of a single vector value in bits
+    pub const SCALAR_BITS: usize = 32;
+
     /// Returns `Vec4f` that contains four `f32` values that are equal to the arguments
     ///
     /// # Examples
@@ -78,6 +163,35 @@ impl Vec4f {
         self * self
     }
 
+    /// Raises every value of the vector to the power of `3` and returns a copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, -3.0, 0.5);
+    /// assert_eq!(vec.cubed(), [1.0, 8.0, -27.0, 0.125]);
+    /// ```
+    pub fn cubed(self) -> Self {
+        self * self * self
+    }
+
+    /// Returns a vector containing reciprocals of square roots of all values (i.e.
+    /// `1 / self.sqrt()`). More precise and slower than `approx_rsqrt`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 4.0, 16.0, 0.25);
+    /// assert_eq!(vec.recip_sqrt(), [1.0, 0.5, 0.25, 2.0]);
+    /// ```
+    pub fn recip_sqrt(self) -> Self {
+        Vec4f::from_scalar(1.0) / self.sqrt()
+    }
+
     fn nan_vec() -> Vec4f {
         // These are magic numbers from original Agner Fog's lib
         // https://github.com/vectorclass/version2/blob/master/instrset.h#L415
@@ -95,6 +209,12 @@ impl Vec4f {
     /// assert_eq!(vec.pow(3), [1.0, 8.0, 27.0, 64.0]);
     /// ```
     pub fn pow(self, mut n: i32) -> Self {
+        match n {
+            -1 => return Vec4f::from_scalar(1.0) / self,
+            2 => return self.squared(),
+            3 => return self.cubed(),
+            _ => {}
+        }
         if n < 0 {
             if n == i32::MIN {
                 return Self::nan_vec();
@@ -139,6 +259,22 @@ impl Vec4f {
         unsafe { _mm_storeu_ps(buffer.as_mut_ptr(), self.xmm) }
     }
 
+    /// Returns `true` if the address of `buffer` is divisible by `16`, i.e. it can be used with
+    /// `load_aligned` and `store_aligned`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Align16, Vec4f};
+    ///
+    /// let arr = Align16([0.0f32; 8]);
+    /// assert!(Vec4f::is_aligned(&arr.0));
+    /// assert!(!Vec4f::is_aligned(&arr.0[1..]));
+    /// ```
+    pub fn is_aligned(buffer: &[f32]) -> bool {
+        (buffer.as_ptr() as usize) & 0xf == 0
+    }
+
     /// Copies values of the vector to a mutable slice. Address of the slice must be divisible by
     /// `16`. Is more efficient than `store`
     ///
@@ -166,8 +302,7 @@ impl Vec4f {
         if buffer.len() < 4 {
             panic!("Buffer len not enough to store Vec128f");
         }
-        // check if buffer address is divisible by 16
-        if (buffer.as_ptr() as usize) & 0xf > 0 {
+        if !Self::is_aligned(buffer) {
             panic!("Buffer address is not aligned by 16");
         }
         // SAFETY: sse
@@ -201,14 +336,38 @@ impl Vec4f {
         if buffer.len() < 4 {
             panic!("Buffer len not enough to store Vec128f");
         }
-        // check if buffer address is divisible by 16
-        if (buffer.as_ptr() as usize) & 0xf > 0 {
+        if !Self::is_aligned(buffer) {
             panic!("Buffer address is not aligned by 16");
         }
         // SAFETY: sse
         unsafe { _mm_stream_ps(buffer.as_mut_ptr(), self.xmm) }
     }
 
+    /// Copies values of the vector to a mutable slice, uses `store_aligned` if the address of the
+    /// slice is divisible by `16` and `store` otherwise
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than `4`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let mut arr = [0.0f32; 5];
+    /// vec.store_auto(&mut arr[1..]);
+    /// assert_eq!(arr, [0.0, 1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn store_auto(self, buffer: &mut [f32]) {
+        if Self::is_aligned(buffer) {
+            self.store_aligned(buffer);
+        } else {
+            self.store(buffer);
+        }
+    }
+
     /// Copies values of the vector to a mutable slice. Works for slices with size less than `4`
     ///
     /// # Examples
@@ -231,6 +390,30 @@ impl Vec4f {
         buffer.copy_from_slice(&values[..buffer.len()]);
     }
 
+    /// Copies values of the vector to a mutable slice of exactly `4` values. Unlike
+    /// `store_partial`, mismatched buffer sizes are treated as an error
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is not equal to `4`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let mut arr = [0.0f32; 4];
+    /// vec.store_exact(&mut arr);
+    /// assert_eq!(arr, [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn store_exact(self, buffer: &mut [f32]) {
+        if buffer.len() != 4 {
+            panic!("Buffer len is not equal to vector size");
+        }
+        self.store(buffer);
+    }
+
     /// Loads values from float slice
     ///
     /// # Panics
@@ -281,14 +464,38 @@ impl Vec4f {
         if buffer.len() < 4 {
             panic!("Buffer len not enough to store Vec128f");
         }
-        // check if buffer address is divisible by 16
-        if (buffer.as_ptr() as usize) & 0xf > 0 {
+        if !Self::is_aligned(buffer) {
             panic!("Buffer address is not aligned by 16");
         }
         // SAFETY: sse
         self.xmm = unsafe { _mm_load_ps(buffer.as_ptr()) };
     }
 
+    /// Loads values from float slice, uses `load_aligned` if the address of the slice is
+    /// divisible by `16` and `load` otherwise
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than `4`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let arr = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+    /// let mut vec = Vec4f::default();
+    /// vec.load_auto(&arr[1..]);
+    /// assert_eq!(vec, [2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    pub fn load_auto(&mut self, buffer: &[f32]) {
+        if Self::is_aligned(buffer) {
+            self.load_aligned(buffer);
+        } else {
+            self.load(buffer);
+        }
+    }
+
     /// Copies values from `buffer` slice to the vector. If `buffer.len()` is less than `4`
     /// fills vector's tail with zeroes
     ///
@@ -489,6 +696,78 @@ impl Vec4f {
         }
     }
 
+    /// Rounds all values down to the closest integer and returns modified copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 1.5, -1.5, -2.0);
+    /// assert_eq!(vec.floor(), [1.0, 1.0, -2.0, -2.0]);
+    /// ```
+    pub fn floor(self) -> Self {
+        #[cfg(target_feature = "sse4.1")]
+        {
+            // SAFETY: sse4.1
+            Self {
+                xmm: unsafe { _mm_round_ps(self.xmm, 1 + 8) },
+            }
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        {
+            let truncated = self.truncate();
+            // SAFETY: sse
+            let above: __m128 = unsafe { _mm_cmpgt_ps(truncated.xmm, self.xmm) };
+            // SAFETY: sse
+            let one: __m128 = unsafe { _mm_and_ps(above, _mm_set1_ps(1.0)) };
+            self.fix_rounded(truncated - Vec4f { xmm: one })
+        }
+    }
+
+    /// Rounds all values up to the closest integer and returns modified copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 1.5, -1.5, -2.0);
+    /// assert_eq!(vec.ceil(), [1.0, 2.0, -1.0, -2.0]);
+    /// ```
+    pub fn ceil(self) -> Self {
+        #[cfg(target_feature = "sse4.1")]
+        {
+            // SAFETY: sse4.1
+            Self {
+                xmm: unsafe { _mm_round_ps(self.xmm, 2 + 8) },
+            }
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        {
+            let truncated = self.truncate();
+            // SAFETY: sse
+            let below: __m128 = unsafe { _mm_cmplt_ps(truncated.xmm, self.xmm) };
+            // SAFETY: sse
+            let one: __m128 = unsafe { _mm_and_ps(below, _mm_set1_ps(1.0)) };
+            self.fix_rounded(truncated + Vec4f { xmm: one })
+        }
+    }
+
+    // Values with magnitude of at least 2^23 (and NaN) have no fractional part and may not fit
+    // into i32, so they are kept as is. The sign of `self` is restored to keep -0.0
+    #[cfg(not(target_feature = "sse4.1"))]
+    fn fix_rounded(self, rounded: Vec4f) -> Vec4f {
+        // SAFETY: sse
+        unsafe {
+            let small: __m128 = _mm_cmplt_ps(self.abs().xmm, _mm_set1_ps(8388608.0));
+            let sign: __m128 = _mm_and_ps(self.xmm, _mm_set1_ps(-0.0));
+            Vec4f {
+                xmm: selectf(small, _mm_or_ps(rounded.xmm, sign), self.xmm),
+            }
+        }
+    }
+
     /// Returns a vector containing square roots of all values of original vector
     ///
     /// # Examples
@@ -599,6 +878,35 @@ impl Vec4f {
         }
     }
 
+    /// Changes signs of vector values chosen by `flips` at runtime, returns modified copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-2.0, 3.0, 2.0, -1.0);
+    /// assert_eq!(
+    ///     vec.change_sign_runtime([true, false, true, false]),
+    ///     [2.0, 3.0, -2.0, -1.0]
+    /// );
+    /// ```
+    pub fn change_sign_runtime(self, flips: [bool; 4]) -> Self {
+        // SAFETY: sse2
+        let mask: __m128i = unsafe {
+            _mm_setr_epi32(
+                Self::mask_helper(flips[0]),
+                Self::mask_helper(flips[1]),
+                Self::mask_helper(flips[2]),
+                Self::mask_helper(flips[3]),
+            )
+        };
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_xor_ps(self.xmm, _mm_castsi128_ps(mask)) },
+        }
+    }
+
     /// Returns a vector containing absolute values of the original vector
     ///
     /// # Examples
@@ -651,6 +959,43 @@ impl Vec4f {
         }
     }
 
+    /// Calculates the sum of all vector values. Never fails and always returns the same value as
+    /// `horizontal_add`, exists for consistency with `checked_horizontal_add`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.try_horizontal_add(), 10.0);
+    /// ```
+    pub fn try_horizontal_add(self) -> f32 {
+        self.horizontal_add()
+    }
+
+    /// Calculates the sum of all vector values. Returns `None` if any value of the vector is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.checked_horizontal_add(), Some(10.0));
+    ///
+    /// let nan = Vec4f::new(1.0, f32::NAN, 3.0, 4.0);
+    /// assert_eq!(nan.checked_horizontal_add(), None);
+    /// ```
+    pub fn checked_horizontal_add(self) -> Option<f32> {
+        // SAFETY: sse
+        let unordered: i32 = unsafe { _mm_movemask_ps(_mm_cmpunord_ps(self.xmm, self.xmm)) };
+        if unordered != 0 {
+            return None;
+        }
+        Some(self.horizontal_add())
+    }
+
     /// Chooses maximum for each index from two vectors, returns the result
     ///
     /// # Examples
@@ -705,59 +1050,2054 @@ impl Vec4f {
     pub fn sign_combine(a: Vec4f, b: Vec4f) -> Vec4f {
         a ^ (b & Vec4f::from_scalar(-0.0f32))
     }
-}
 
-/// Constructs vector from array
-///
-/// # Examples
-///
-/// ```
-/// use vcl_rust::Vec4f;
-///
-/// let vec = Vec4f::from([1.0, 2.0, 3.0, 4.0]);
-/// assert_eq!(vec, [1.0, 2.0, 3.0, 4.0]);
-/// ```
-impl core::convert::From<[f32; 4]> for Vec4f {
-    fn from(value: [f32; 4]) -> Self {
+    /// Swaps neighbouring values in pairs and returns the result, i.e. `[x1, x0, x3, x2]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.swap_pairs(), [2.0, 1.0, 4.0, 3.0]);
+    /// ```
+    pub fn swap_pairs(self) -> Self {
         Self {
             // SAFETY: sse
-            xmm: unsafe { _mm_loadu_ps(value.as_ptr()) },
+            xmm: unsafe { _mm_shuffle_ps(self.xmm, self.xmm, 0xB1) },
         }
     }
-}
 
-/// Constructs vector from slice
-///
-/// # Panics
-///
-/// Panics if slice size is less than `4`
-///
-/// # Examples
-///
-/// ```
-/// use vcl_rust::Vec4f;
-///
-/// let arr = vec![-1.0, 2.0, 3.0, 1.5];
-/// let vec = Vec4f::from(arr.as_slice());
-/// assert_eq!(vec, [-1.0, 2.0, 3.0, 1.5]);
-/// ```
-impl core::convert::From<&[f32]> for Vec4f {
-    fn from(value: &[f32]) -> Self {
-        if value.len() < 4 {
-            panic!("Slice size is not enough to construct a vector");
+    /// Moves the high half of the vector to the low half and returns the result, i.e.
+    /// `[x2, x3, x2, x3]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.movehl(), [3.0, 4.0, 3.0, 4.0]);
+    /// ```
+    pub fn movehl(self) -> Self {
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_movehl_ps(self.xmm, self.xmm) },
         }
+    }
+
+    /// Moves the low half of the vector to the high half and returns the result, i.e.
+    /// `[x0, x1, x0, x1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.movelh(), [1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    pub fn movelh(self) -> Self {
         Self {
             // SAFETY: sse
-            xmm: unsafe { _mm_loadu_ps(value.as_ptr()) },
+            xmm: unsafe { _mm_movelh_ps(self.xmm, self.xmm) },
         }
     }
-}
 
-/// Creates vector initialized with `0.0` values
-///
-/// # Examples
-///
-/// ```
+    fn clamp_scalar(self, lo: f32, hi: f32) -> Self {
+        Vec4f::min(
+            Vec4f::max(self, Vec4f::from_scalar(lo)),
+            Vec4f::from_scalar(hi),
+        )
+    }
+
+    /// Adds `rhs` to the vector and clamps every value of the sum to `[lo, hi]`, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let sum = vec.add_clamped(Vec4f::new(-3.0, 1.0, 1.0, 1.0), 0.0, 4.5);
+    /// assert_eq!(sum, [0.0, 3.0, 4.0, 4.5]);
+    /// ```
+    pub fn add_clamped(self, rhs: Vec4f, lo: f32, hi: f32) -> Self {
+        (self + rhs).clamp_scalar(lo, hi)
+    }
+
+    /// Substracts `rhs` from the vector and clamps every value of the difference to `[lo, hi]`,
+    /// returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let diff = vec.sub_clamped(Vec4f::new(3.0, 1.0, 1.0, -1.0), 0.0, 4.5);
+    /// assert_eq!(diff, [0.0, 1.0, 2.0, 4.5]);
+    /// ```
+    pub fn sub_clamped(self, rhs: Vec4f, lo: f32, hi: f32) -> Self {
+        (self - rhs).clamp_scalar(lo, hi)
+    }
+
+    /// Compares every value of the vector with corresponding value of `other`, returns a mask
+    /// which lanes are `true` where values differ by no more than `epsilon`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4f::new(1.05, 2.5, 3.0, 3.0);
+    /// assert_eq!(a.approx_eq_mask(b, 0.1), [true, false, true, false]);
+    /// ```
+    pub fn approx_eq_mask(self, other: Vec4f, epsilon: f32) -> Vec4fb {
+        let diff = (self - other).abs();
+        Vec4fb {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmple_ps(diff.xmm, _mm_set1_ps(epsilon)) },
+        }
+    }
+
+    /// Rounds every value of the vector to the closest multiple of `step` and returns modified
+    /// copy. Returns the vector unchanged if `step` is `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.1, 2.6, -0.4, 3.5);
+    /// assert_eq!(vec.round_to_multiple(0.5), [1.0, 2.5, -0.5, 3.5]);
+    /// assert_eq!(vec.round_to_multiple(0.0), vec);
+    /// ```
+    pub fn round_to_multiple(self, step: f32) -> Self {
+        if step == 0.0 {
+            return self;
+        }
+        let step = Vec4f::from_scalar(step);
+        (self / step).round() * step
+    }
+
+    /// Returns a mask which lanes are `true` where neither `self` nor `other` value is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, f32::NAN, 3.0, 4.0);
+    /// let b = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// assert_eq!(a.cmp_ordered(b), [true, false, true, false]);
+    /// ```
+    pub fn cmp_ordered(self, other: Vec4f) -> Vec4fb {
+        Vec4fb {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpord_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Returns a mask which lanes are `true` where `self` or `other` value is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, f32::NAN, 3.0, 4.0);
+    /// let b = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// assert_eq!(a.cmp_unordered(b), [false, true, false, true]);
+    /// ```
+    pub fn cmp_unordered(self, other: Vec4f) -> Vec4fb {
+        Vec4fb {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpunord_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is less than `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_lt(b) & ones, [1.0, 0.0, 0.0, 0.0]);
+    /// ```
+    pub fn cmp_lt(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmplt_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is less than or equal to `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_le(b) & ones, [1.0, 1.0, 0.0, 0.0]);
+    /// ```
+    pub fn cmp_le(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmple_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is greater than `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_gt(b) & ones, [0.0, 0.0, 1.0, 0.0]);
+    /// ```
+    pub fn cmp_gt(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpgt_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is greater than or equal to `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_ge(b) & ones, [0.0, 1.0, 1.0, 0.0]);
+    /// ```
+    pub fn cmp_ge(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpge_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is equal to `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_eq(b) & ones, [0.0, 1.0, 0.0, 0.0]);
+    /// ```
+    pub fn cmp_eq(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpeq_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Compares vectors elementwise and returns a mask vector which lanes have all bits set where
+    /// `self` value is not equal to `other` value and all bits cleared otherwise. Lanes with NaN
+    /// are `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, f32::NAN);
+    /// let b = Vec4f::new(2.0, 2.0, 2.0, 2.0);
+    /// let ones = Vec4f::from_scalar(1.0);
+    /// assert_eq!(a.cmp_ne(b) & ones, [1.0, 0.0, 1.0, 1.0]);
+    /// ```
+    pub fn cmp_ne(self, other: Vec4f) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_cmpneq_ps(self.xmm, other.xmm) },
+        }
+    }
+
+    /// Divides the vector by `divisor` and returns the result. Lanes where `divisor` is `0.0`
+    /// (or `-0.0`) are replaced by `fallback`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4f::new(2.0, 0.0, -1.0, 0.5);
+    /// assert_eq!(a.div_safe(b, -1.0), [0.5, -1.0, -3.0, 8.0]);
+    /// ```
+    pub fn div_safe(self, divisor: Vec4f, fallback: f32) -> Self {
+        // SAFETY: sse
+        let zero: __m128 = unsafe { _mm_cmpeq_ps(divisor.xmm, _mm_setzero_ps()) };
+        Self {
+            // SAFETY: sse
+            xmm: selectf(zero, unsafe { _mm_set1_ps(fallback) }, (self / divisor).xmm),
+        }
+    }
+
+    /// Divides the vector by it's euclidean length in place. A vector of zero length is left
+    /// unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let mut vec = Vec4f::new(3.0, 0.0, 4.0, 0.0);
+    /// vec.normalize_assign();
+    /// assert_eq!(vec, [0.6, 0.0, 0.8, 0.0]);
+    /// ```
+    pub fn normalize_assign(&mut self) {
+        let length_squared = self.squared().horizontal_add();
+        if length_squared == 0.0 {
+            return;
+        }
+        *self /= Vec4f::from_scalar(length_squared).sqrt();
+    }
+
+    /// Returns a vector that contains values of `a` where `mask` is `true` and `scalar` elsewhere
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4fb};
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let mask = Vec4fb::new(true, false, false, true);
+    /// assert_eq!(Vec4f::select_or(mask, a, -1.0), [1.0, -1.0, -1.0, 4.0]);
+    /// ```
+    pub fn select_or(mask: Vec4fb, a: Vec4f, scalar: f32) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse
+            xmm: selectf(mask.xmm, a.xmm, unsafe { _mm_set1_ps(scalar) }),
+        }
+    }
+
+    /// Calculates the sum of all vector values that are not NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::NAN, 3.0, 4.0);
+    /// assert_eq!(vec.horizontal_add_ignore_nan(), 8.0);
+    /// ```
+    pub fn horizontal_add_ignore_nan(self) -> f32 {
+        self.horizontal_add_ignore_nan_count().0
+    }
+
+    /// Calculates the sum of all vector values that are not NaN, returns it together with the
+    /// number of summed values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::NAN, 3.0, 4.0);
+    /// assert_eq!(vec.horizontal_add_ignore_nan_count(), (8.0, 3));
+    /// ```
+    pub fn horizontal_add_ignore_nan_count(self) -> (f32, usize) {
+        let ordered = self.cmp_ordered(self);
+        let sum = Vec4f {
+            // SAFETY: sse
+            xmm: unsafe { _mm_and_ps(self.xmm, ordered.xmm) },
+        }
+        .horizontal_add();
+        (sum, ordered.to_bits().count_ones() as usize)
+    }
+
+    /// Returns a vector that contains `value` at `index` and `0.0` elsewhere
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is greater than 3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert_eq!(Vec4f::one_hot(2, 5.0), [0.0, 0.0, 5.0, 0.0]);
+    /// ```
+    pub fn one_hot(index: usize, value: f32) -> Self {
+        Vec4f::default().insert(index, value)
+    }
+
+    /// Copies values of the vector to an array aligned by `16` bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let arr = vec.to_aligned_array();
+    /// assert_eq!(arr.0, [1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(arr.0.as_ptr() as usize % 16, 0);
+    /// ```
+    pub fn to_aligned_array(self) -> Align16<[f32; 4]> {
+        let mut arr = Align16([0.0f32; 4]);
+        // SAFETY: sse, address of Align16 is divisible by 16
+        unsafe { _mm_store_ps(arr.0.as_mut_ptr(), self.xmm) };
+        arr
+    }
+
+    /// Reinterprets vector values as `u32` bit patterns and returns bitwise AND of all of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 1.5, -1.0, 1.25);
+    /// assert_eq!(vec.horizontal_and_bits(), 1.0f32.to_bits());
+    /// ```
+    pub fn horizontal_and_bits(self) -> u32 {
+        // SAFETY: sse2
+        unsafe {
+            let t1: __m128i = _mm_castps_si128(self.xmm);
+            let t2: __m128i = _mm_and_si128(t1, _mm_shuffle_epi32(t1, 0x4E));
+            let t3: __m128i = _mm_and_si128(t2, _mm_shuffle_epi32(t2, 0xB1));
+            _mm_cvtsi128_si32(t3) as u32
+        }
+    }
+
+    /// Reinterprets vector values as `u32` bit patterns and returns bitwise OR of all of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(0.0, -0.0, 0.0, 0.0);
+    /// assert_eq!(vec.horizontal_or_bits(), 0x8000_0000);
+    /// ```
+    pub fn horizontal_or_bits(self) -> u32 {
+        // SAFETY: sse2
+        unsafe {
+            let t1: __m128i = _mm_castps_si128(self.xmm);
+            let t2: __m128i = _mm_or_si128(t1, _mm_shuffle_epi32(t1, 0x4E));
+            let t3: __m128i = _mm_or_si128(t2, _mm_shuffle_epi32(t2, 0xB1));
+            _mm_cvtsi128_si32(t3) as u32
+        }
+    }
+
+    /// Reinterprets vector values as `u32` bit patterns and returns bitwise XOR of all of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 1.0, 2.0, 2.0);
+    /// assert_eq!(vec.horizontal_xor_bits(), 0);
+    /// ```
+    pub fn horizontal_xor_bits(self) -> u32 {
+        // SAFETY: sse2
+        unsafe {
+            let t1: __m128i = _mm_castps_si128(self.xmm);
+            let t2: __m128i = _mm_xor_si128(t1, _mm_shuffle_epi32(t1, 0x4E));
+            let t3: __m128i = _mm_xor_si128(t2, _mm_shuffle_epi32(t2, 0xB1));
+            _mm_cvtsi128_si32(t3) as u32
+        }
+    }
+
+    /// Returns the maximum of vector values `[x0, x1, x2, x3]`, computed as
+    /// `max(max(x0, x2), max(x1, x3))` where every `max` is `_mm_max_ps`, which returns its
+    /// second operand if any operand is NaN. Because of that NaN in `x0` or `x1` is ignored, NaN in
+    /// `x2` makes the result `max(x1, x3)` and NaN in `x3` makes the result NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 8.0, -3.0, 4.0);
+    /// assert_eq!(vec.horizontal_max(), 8.0);
+    /// assert_eq!(vec.insert(0, f32::NAN).horizontal_max(), 8.0);
+    /// assert!(vec.insert(3, f32::NAN).horizontal_max().is_nan());
+    /// ```
+    pub fn horizontal_max(self) -> f32 {
+        horizontal_maxf(self.xmm)
+    }
+
+    /// Returns the minimum of vector values `[x0, x1, x2, x3]`, computed as
+    /// `min(min(x0, x2), min(x1, x3))` where every `min` is `_mm_min_ps`, which returns its
+    /// second operand if any operand is NaN. Because of that NaN in `x0` or `x1` is ignored, NaN in
+    /// `x2` makes the result `min(x1, x3)` and NaN in `x3` makes the result NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 8.0, -3.0, 4.0);
+    /// assert_eq!(vec.horizontal_min(), -3.0);
+    /// assert_eq!(vec.insert(0, f32::NAN).horizontal_min(), -3.0);
+    /// assert!(vec.insert(3, f32::NAN).horizontal_min().is_nan());
+    /// ```
+    pub fn horizontal_min(self) -> f32 {
+        horizontal_minf(self.xmm)
+    }
+
+    /// Returns the maximum of vector values for which `mask` is `true`. Returns `f32::NEG_INFINITY`
+    /// if no value is selected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4fb};
+    ///
+    /// let vec = Vec4f::new(1.0, 8.0, 3.0, 4.0);
+    /// let mask = Vec4fb::new(true, false, true, false);
+    /// assert_eq!(vec.horizontal_max_masked(mask), 3.0);
+    /// ```
+    pub fn horizontal_max_masked(self, mask: Vec4fb) -> f32 {
+        // SAFETY: sse
+        let masked = selectf(mask.xmm, self.xmm, unsafe {
+            _mm_set1_ps(f32::NEG_INFINITY)
+        });
+        horizontal_maxf(masked)
+    }
+
+    /// Returns the minimum of vector values for which `mask` is `true`. Returns `f32::INFINITY`
+    /// if no value is selected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4fb};
+    ///
+    /// let vec = Vec4f::new(1.0, 8.0, 3.0, 4.0);
+    /// let mask = Vec4fb::new(false, true, false, true);
+    /// assert_eq!(vec.horizontal_min_masked(mask), 4.0);
+    /// ```
+    pub fn horizontal_min_masked(self, mask: Vec4fb) -> f32 {
+        // SAFETY: sse
+        let masked = selectf(mask.xmm, self.xmm, unsafe { _mm_set1_ps(f32::INFINITY) });
+        horizontal_minf(masked)
+    }
+
+    /// Writes four vectors to `out` interleaved, i.e. `[x0, y0, z0, w0, x1, y1, z1, w1, ...]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len()` is less than `16`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let x = Vec4f::new(0.0, 1.0, 2.0, 3.0);
+    /// let y = Vec4f::new(10.0, 11.0, 12.0, 13.0);
+    /// let z = Vec4f::new(20.0, 21.0, 22.0, 23.0);
+    /// let w = Vec4f::new(30.0, 31.0, 32.0, 33.0);
+    /// let mut out = [0.0f32; 16];
+    /// Vec4f::store_interleaved4(x, y, z, w, &mut out);
+    /// assert_eq!(out[..4], [0.0, 10.0, 20.0, 30.0]);
+    /// assert_eq!(out[12..], [3.0, 13.0, 23.0, 33.0]);
+    /// ```
+    pub fn store_interleaved4(x: Vec4f, y: Vec4f, z: Vec4f, w: Vec4f, out: &mut [f32]) {
+        if out.len() < 16 {
+            panic!("Buffer len not enough to store four Vec128f");
+        }
+        let rows = transpose4(x.xmm, y.xmm, z.xmm, w.xmm);
+        for (row, chunk) in rows.iter().zip(out.chunks_exact_mut(4)) {
+            Vec4f { xmm: *row }.store(chunk);
+        }
+    }
+
+    /// Reads sixteen interleaved values from `data` (`[x0, y0, z0, w0, x1, y1, z1, w1, ...]`) and
+    /// returns them as four vectors `(x, y, z, w)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data.len()` is less than `16`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let data: [f32; 16] = [
+    ///     0.0, 10.0, 20.0, 30.0, 1.0, 11.0, 21.0, 31.0, 2.0, 12.0, 22.0, 32.0, 3.0, 13.0, 23.0, 33.0,
+    /// ];
+    /// let (x, y, z, w) = Vec4f::load_interleaved4(&data);
+    /// assert_eq!(x, [0.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(w, [30.0, 31.0, 32.0, 33.0]);
+    /// ```
+    pub fn load_interleaved4(data: &[f32]) -> (Vec4f, Vec4f, Vec4f, Vec4f) {
+        if data.len() < 16 {
+            panic!("Buffer len not enough to load four vectors");
+        }
+        let [x, y, z, w] = transpose4(
+            Vec4f::from(&data[0..4]).xmm,
+            Vec4f::from(&data[4..8]).xmm,
+            Vec4f::from(&data[8..12]).xmm,
+            Vec4f::from(&data[12..16]).xmm,
+        );
+        (
+            Vec4f { xmm: x },
+            Vec4f { xmm: y },
+            Vec4f { xmm: z },
+            Vec4f { xmm: w },
+        )
+    }
+
+    /// Returns a bitmask where bit `i` is set if value `i` is not equal to `0.0` (`-0.0` counts
+    /// as zero)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 0.0, -0.0, -3.0);
+    /// assert_eq!(vec.nonzero_mask(), 0b1001);
+    /// ```
+    pub fn nonzero_mask(self) -> u8 {
+        // SAFETY: sse
+        let zero: i32 = unsafe { _mm_movemask_ps(_mm_cmpeq_ps(self.xmm, Vec4f::default().xmm)) };
+        (!zero & 0x0F) as u8
+    }
+
+    /// Clamps every value of the vector to the range between corresponding values of `lo` and
+    /// `hi`. Returns the clamped vector together with a mask which lanes are `true` where values
+    /// were outside the range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-2.0, 0.5, 3.0, 1.0);
+    /// let (clamped, mask) = vec.clamp_reported(Vec4f::from_scalar(0.0), Vec4f::from_scalar(1.0));
+    /// assert_eq!(clamped, [0.0, 0.5, 1.0, 1.0]);
+    /// assert_eq!(mask, [true, false, true, false]);
+    /// ```
+    pub fn clamp_reported(self, lo: Vec4f, hi: Vec4f) -> (Vec4f, Vec4fb) {
+        let clamped = Vec4f::min(Vec4f::max(self, lo), hi);
+        let mask = Vec4fb {
+            // SAFETY: sse
+            xmm: unsafe {
+                _mm_or_ps(
+                    _mm_cmplt_ps(self.xmm, lo.xmm),
+                    _mm_cmpgt_ps(self.xmm, hi.xmm),
+                )
+            },
+        };
+        (clamped, mask)
+    }
+
+    /// Returns a mask which lanes are `true` where values are subnormal, i.e. exponent bits are
+    /// zero and mantissa bits are not
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::MIN_POSITIVE / 2.0, 0.0, -f32::from_bits(1));
+    /// assert_eq!(vec.is_subnormal(), [false, true, false, true]);
+    /// ```
+    pub fn is_subnormal(self) -> Vec4fb {
+        // SAFETY: sse2
+        unsafe {
+            let bits: __m128i = _mm_castps_si128(self.xmm);
+            let zero: __m128i = _mm_setzero_si128();
+            let exponent: __m128i = _mm_and_si128(bits, _mm_set1_epi32(0x7F80_0000));
+            let mantissa: __m128i = _mm_and_si128(bits, _mm_set1_epi32(0x007F_FFFF));
+            let exponent_zero: __m128i = _mm_cmpeq_epi32(exponent, zero);
+            let mantissa_zero: __m128i = _mm_cmpeq_epi32(mantissa, zero);
+            Vec4fb {
+                xmm: _mm_castsi128_ps(_mm_andnot_si128(mantissa_zero, exponent_zero)),
+            }
+        }
+    }
+
+    /// Replaces negative values of the vector with `0.0` and returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-1.0, 2.0, -3.0, 4.0);
+    /// assert_eq!(vec.relu(), [0.0, 2.0, 0.0, 4.0]);
+    /// ```
+    pub fn relu(self) -> Self {
+        Vec4f::max(self, Vec4f::default())
+    }
+
+    /// Multiplies negative values of the vector by `alpha` and returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-1.0, 2.0, -3.0, 4.0);
+    /// assert_eq!(vec.leaky_relu(0.5), [-0.5, 2.0, -1.5, 4.0]);
+    /// ```
+    pub fn leaky_relu(self, alpha: f32) -> Self {
+        // SAFETY: sse
+        let negative: __m128 = unsafe { _mm_cmplt_ps(self.xmm, _mm_setzero_ps()) };
+        Self {
+            xmm: selectf(negative, (self * Vec4f::from_scalar(alpha)).xmm, self.xmm),
+        }
+    }
+
+    /// Fast approximation of hyperbolic tangent using the Padé approximant
+    /// `x * (27 + x^2) / (27 + 9x^2)`. Input is clamped to `[-3.0, 3.0]`, where the approximant
+    /// reaches exactly `±1.0` with zero slope, so the result saturates smoothly. Absolute error is
+    /// below `1e-3` for `|x| <= 0.3` and below `2.5e-2` elsewhere, which is enough for audio
+    /// waveshaping but not for precise calculations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-10.0, 0.0, 0.25, 3.0);
+    /// let tanh = vec.tanh_fast();
+    /// assert_eq!(tanh[0], -1.0);
+    /// assert_eq!(tanh[1], 0.0);
+    /// assert!((tanh[2] - 0.24492).abs() < 1e-3);
+    /// assert_eq!(tanh[3], 1.0);
+    /// ```
+    pub fn tanh_fast(self) -> Self {
+        let x = self.clamp_scalar(-3.0, 3.0);
+        let x2 = x.squared();
+        let c = Vec4f::from_scalar(27.0);
+        x * (c + x2) / (c + Vec4f::from_scalar(9.0) * x2)
+    }
+
+    /// Calculates the dot product of `a` and `b` and returns a vector with the result in every
+    /// lane
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4f::new(2.0, 0.5, -1.0, 1.0);
+    /// assert_eq!(Vec4f::dot_broadcast(a, b), [4.0; 4]);
+    /// ```
+    pub fn dot_broadcast(a: Vec4f, b: Vec4f) -> Vec4f {
+        #[cfg(target_feature = "sse4.1")]
+        {
+            // SAFETY: sse4.1
+            Vec4f {
+                xmm: unsafe { _mm_dp_ps(a.xmm, b.xmm, 0xFF) },
+            }
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        {
+            // SAFETY: sse
+            unsafe {
+                let t1: __m128 = _mm_mul_ps(a.xmm, b.xmm);
+                let t2: __m128 = _mm_add_ps(t1, _mm_shuffle_ps(t1, t1, 0x4E));
+                Vec4f {
+                    xmm: _mm_add_ps(t2, _mm_shuffle_ps(t2, t2, 0xB1)),
+                }
+            }
+        }
+    }
+
+    /// Returns a mask which lanes are `true` where values are neither infinite nor NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::NAN, f32::INFINITY, -0.0);
+    /// assert_eq!(vec.is_finite(), [true, false, false, true]);
+    /// ```
+    pub fn is_finite(self) -> Vec4fb {
+        // SAFETY: sse2
+        unsafe {
+            let exponent_mask: __m128i = _mm_set1_epi32(0x7F80_0000);
+            let exponent: __m128i = _mm_and_si128(_mm_castps_si128(self.xmm), exponent_mask);
+            let all_ones: __m128i = _mm_cmpeq_epi32(exponent, exponent_mask);
+            Vec4fb {
+                xmm: _mm_castsi128_ps(_mm_xor_si128(all_ones, _mm_set1_epi32(-1))),
+            }
+        }
+    }
+
+    /// Replaces infinite and NaN values of the vector with `0.0` and returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::NAN, f32::INFINITY, -2.0);
+    /// assert_eq!(vec.finite_or_zero(), [1.0, 0.0, 0.0, -2.0]);
+    /// ```
+    pub fn finite_or_zero(self) -> Self {
+        Self {
+            xmm: selectf(self.is_finite().xmm, self.xmm, Vec4f::default().xmm),
+        }
+    }
+
+    /// Returns a vector containing absolute values of the original vector with the sign of `sign`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-2.0, 3.0, 0.0, -1.0);
+    /// assert_eq!(vec.copysign_scalar(-1.0), [-2.0, -3.0, -0.0, -1.0]);
+    /// assert_eq!(vec.copysign_scalar(1.0), [2.0, 3.0, 0.0, 1.0]);
+    /// ```
+    pub fn copysign_scalar(self, sign: f32) -> Self {
+        self.abs() | (Vec4f::from_scalar(sign) & Vec4f::from_scalar(-0.0f32))
+    }
+
+    /// Returns a vector `[start, start + step, start + 2 * step, start + 3 * step]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert_eq!(Vec4f::iota(1.0, 0.5), [1.0, 1.5, 2.0, 2.5]);
+    /// ```
+    pub fn iota(start: f32, step: f32) -> Self {
+        Vec4f::from_scalar(start) + Vec4f::lane_indices() * Vec4f::from_scalar(step)
+    }
+
+    /// Returns a vector that contains indices of it's lanes, i.e. `[0.0, 1.0, 2.0, 3.0]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert_eq!(Vec4f::lane_indices(), [0.0, 1.0, 2.0, 3.0]);
+    /// ```
+    pub fn lane_indices() -> Self {
+        Vec4f::new(0.0, 1.0, 2.0, 3.0)
+    }
+
+    /// Swaps values of `a` and `b` in lanes where `mask` is `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4fb};
+    ///
+    /// let mut a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let mut b = Vec4f::new(5.0, 6.0, 7.0, 8.0);
+    /// Vec4f::conditional_swap(&mut a, &mut b, Vec4fb::new(true, false, false, true));
+    /// assert_eq!(a, [5.0, 2.0, 3.0, 8.0]);
+    /// assert_eq!(b, [1.0, 6.0, 7.0, 4.0]);
+    /// ```
+    pub fn conditional_swap(a: &mut Vec4f, b: &mut Vec4f, mask: Vec4fb) {
+        let new_a = selectf(mask.xmm, b.xmm, a.xmm);
+        let new_b = selectf(mask.xmm, a.xmm, b.xmm);
+        a.xmm = new_a;
+        b.xmm = new_b;
+    }
+
+    /// Returns a pair of vectors containing minimum and maximum for each index of `a` and `b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(5.0, -2.0, 3.0, 1.0);
+    /// let b = Vec4f::new(1.0, 2.0, 2.0, 11.0);
+    /// let (lo, hi) = Vec4f::minmax(a, b);
+    /// assert_eq!(lo, [1.0, -2.0, 2.0, 1.0]);
+    /// assert_eq!(hi, [5.0, 2.0, 3.0, 11.0]);
+    /// ```
+    pub fn minmax(a: Vec4f, b: Vec4f) -> (Vec4f, Vec4f) {
+        (Vec4f::min(a, b), Vec4f::max(a, b))
+    }
+
+    /// Returns `true` if every value of the vector is in `[lo, hi]`. NaN values are never in
+    /// range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(0.0, 0.5, 0.75, 1.0);
+    /// assert!(vec.all_in_range(0.0, 1.0));
+    /// assert!(!vec.all_in_range(0.0, 0.9));
+    /// ```
+    pub fn all_in_range(self, lo: f32, hi: f32) -> bool {
+        // SAFETY: sse
+        let inside: i32 = unsafe {
+            _mm_movemask_ps(_mm_and_ps(
+                _mm_cmpge_ps(self.xmm, _mm_set1_ps(lo)),
+                _mm_cmple_ps(self.xmm, _mm_set1_ps(hi)),
+            ))
+        };
+        inside == 0x0F
+    }
+
+    /// Adds every value of the vector to the next one and returns the result, i.e.
+    /// `[x0 + x1, x1 + x2, x2 + x3, x3]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.window_sum2(), [3.0, 5.0, 7.0, 4.0]);
+    /// ```
+    pub fn window_sum2(self) -> Self {
+        self + Vec4f {
+            xmm: shift_down(self.xmm),
+        }
+    }
+
+    /// Divides the vector by `divisor` and returns the result. Lanes where absolute value of
+    /// `divisor` is less than `tol` are replaced by NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4f::new(2.0, 1e-9, -1.0, 0.0);
+    /// let c = a.div_or_nan(b, 1e-6);
+    /// assert_eq!(c[0], 0.5);
+    /// assert!(c[1].is_nan());
+    /// assert_eq!(c[2], -3.0);
+    /// assert!(c[3].is_nan());
+    /// ```
+    pub fn div_or_nan(self, divisor: Vec4f, tol: f32) -> Self {
+        // SAFETY: sse
+        let singular: __m128 = unsafe { _mm_cmplt_ps(divisor.abs().xmm, _mm_set1_ps(tol)) };
+        Self {
+            xmm: selectf(singular, Self::nan_vec().xmm, (self / divisor).xmm),
+        }
+    }
+
+    /// Calculates the geometric mean of vector values, i.e. fourth root of their product.
+    /// Returns NaN if any value is negative. The product is calculated in `f32`, so very large or
+    /// very small values can overflow or underflow
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 4.0, 8.0);
+    /// assert!((vec.geometric_mean() - 2.828427).abs() < 0.00001);
+    /// assert!(Vec4f::new(1.0, -2.0, 4.0, 8.0).geometric_mean().is_nan());
+    /// ```
+    pub fn geometric_mean(self) -> f32 {
+        // SAFETY: sse
+        let (negative, product) = unsafe {
+            let negative: i32 = _mm_movemask_ps(_mm_cmplt_ps(self.xmm, _mm_setzero_ps()));
+            let t1: __m128 = _mm_mul_ps(self.xmm, _mm_movehl_ps(self.xmm, self.xmm));
+            let t2: __m128 = _mm_mul_ss(t1, _mm_shuffle_ps(t1, t1, 1));
+            (negative, _mm_cvtss_f32(t2))
+        };
+        if negative != 0 {
+            return f32::NAN;
+        }
+        Vec4f::from_scalar(product).sqrt().sqrt()[0]
+    }
+
+    /// Chooses maximum for each index from three vectors, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(5.0, -2.0, 3.0, 1.0);
+    /// let b = Vec4f::new(1.0, 2.0, 2.0, 11.0);
+    /// let c = Vec4f::new(0.0, 0.0, 4.0, 0.0);
+    /// assert_eq!(Vec4f::max3(a, b, c), [5.0, 2.0, 4.0, 11.0]);
+    /// ```
+    pub fn max3(a: Vec4f, b: Vec4f, c: Vec4f) -> Vec4f {
+        Vec4f::max(Vec4f::max(a, b), c)
+    }
+
+    /// Chooses minimum for each index from three vectors, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(5.0, -2.0, 3.0, 1.0);
+    /// let b = Vec4f::new(1.0, 2.0, 2.0, 11.0);
+    /// let c = Vec4f::new(0.0, 0.0, 4.0, 0.0);
+    /// assert_eq!(Vec4f::min3(a, b, c), [0.0, -2.0, 2.0, 0.0]);
+    /// ```
+    pub fn min3(a: Vec4f, b: Vec4f, c: Vec4f) -> Vec4f {
+        Vec4f::min(Vec4f::min(a, b), c)
+    }
+
+    /// Folds vector values from the first to the last with `f` starting from `init`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.fold_lanes(0.0, |acc, x| acc * 10.0 + x), 1234.0);
+    /// ```
+    pub fn fold_lanes<F: Fn(f32, f32) -> f32>(self, init: f32, f: F) -> f32 {
+        let mut values = [0.0f32; 4];
+        self.store(&mut values);
+        values.iter().fold(init, |acc, &x| f(acc, x))
+    }
+
+    /// Returns a vector with all values equal to the value at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is greater than 3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(vec.broadcast_lane(2), [3.0; 4]);
+    /// ```
+    pub fn broadcast_lane(self, index: usize) -> Self {
+        Vec4f::from_scalar(self[index])
+    }
+
+    /// Formats vector values as comma separated string, e.g. `"1,2.5,-3,4"`. The output can be
+    /// parsed back with `from_csv`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.5, -3.0, 4.0);
+    /// assert_eq!(vec.to_csv(), "1,2.5,-3,4");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_csv(self) -> alloc::string::String {
+        let mut values = [0.0f32; 4];
+        self.store(&mut values);
+        alloc::format!("{},{},{},{}", values[0], values[1], values[2], values[3])
+    }
+
+    /// Parses a vector from four comma separated values. Whitespace around values is ignored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{ParseVec4fError, Vec4f};
+    ///
+    /// assert_eq!(Vec4f::from_csv("1, 2.5, -3, 4").unwrap(), [1.0, 2.5, -3.0, 4.0]);
+    /// assert_eq!(Vec4f::from_csv("1,2,3"), Err(ParseVec4fError::WrongLength));
+    /// ```
+    pub fn from_csv(s: &str) -> Result<Vec4f, ParseVec4fError> {
+        let mut values = [0.0f32; 4];
+        let mut parts = s.split(',');
+        for value in values.iter_mut() {
+            let part = parts.next().ok_or(ParseVec4fError::WrongLength)?;
+            *value = part.trim().parse().map_err(ParseVec4fError::InvalidValue)?;
+        }
+        if parts.next().is_some() {
+            return Err(ParseVec4fError::WrongLength);
+        }
+        Ok(Vec4f::from(values))
+    }
+
+    /// Calculates the sum of vector values multiplied by corresponding `weights`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let values = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let weights = Vec4f::new(0.1, 0.2, 0.3, 0.4);
+    /// assert!((values.weighted_sum(weights) - 3.0).abs() < 0.00001);
+    /// ```
+    pub fn weighted_sum(self, weights: Vec4f) -> f32 {
+        (self * weights).horizontal_add()
+    }
+
+    /// Returns the largest and the second largest vector values. Equal values are counted
+    /// separately, so if the maximum occurs twice both returned values are equal to it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert_eq!(Vec4f::new(4.0, 1.0, 3.0, 2.0).top2(), (4.0, 3.0));
+    /// assert_eq!(Vec4f::new(4.0, 1.0, 4.0, 2.0).top2(), (4.0, 4.0));
+    /// ```
+    pub fn top2(self) -> (f32, f32) {
+        // hi = [max01, max01, max23, max23], lo = [min01, min01, min23, min23]
+        let (lo, hi) = Vec4f::minmax(self, self.swap_pairs());
+        let (hi_lo, hi_hi) = Vec4f::minmax(hi, hi.movehl());
+        let lo_hi = Vec4f::max(lo, lo.movehl());
+        (hi_hi[0], Vec4f::max(hi_lo, lo_hi)[0])
+    }
+
+    /// Returns a vector `[even, odd, even, odd]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert_eq!(Vec4f::alternating(1.0, -1.0), [1.0, -1.0, 1.0, -1.0]);
+    /// ```
+    pub fn alternating(even: f32, odd: f32) -> Self {
+        Vec4f::new(even, odd, even, odd)
+    }
+
+    /// Returns `true` if vector values are non-decreasing, allowing every value to be less than
+    /// the previous one by no more than `tol`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// assert!(Vec4f::new(1.0, 2.0, 2.0, 3.0).is_monotonic(0.0));
+    /// assert!(Vec4f::new(1.0, 2.0, 1.95, 3.0).is_monotonic(0.1));
+    /// assert!(!Vec4f::new(1.0, 2.0, 1.5, 3.0).is_monotonic(0.1));
+    /// ```
+    pub fn is_monotonic(self, tol: f32) -> bool {
+        let next = shift_down(self.xmm);
+        let lower = self - Vec4f::from_scalar(tol);
+        // SAFETY: sse
+        let ok: i32 = unsafe { _mm_movemask_ps(_mm_cmpge_ps(next, lower.xmm)) };
+        // the last lane has no next value
+        ok & 0b0111 == 0b0111
+    }
+
+    /// Approximate reverse square root (i.e. `1 / self.sqrt()`) using the integer bit trick with
+    /// the `0x5f3759df` constant and one Newton-Raphson step. Relative error is below `0.2%` for
+    /// all positive finite values including subnormals, which is worse than `approx_rsqrt`, but
+    /// the result doesn't depend on the processor. Edge cases match `1 / self.sqrt()`: `±0.0`
+    /// gives `±inf`, `inf` gives `0.0`, negative values and NaN give NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 4.0, 16.0, 0.25);
+    /// let approx = vec.rsqrt_bittrick();
+    /// let rsqrt = Vec4f::from_scalar(1.0) / vec.sqrt();
+    ///
+    /// let mut arr = [0.0f32; 4];
+    /// let diff = (approx - rsqrt) / rsqrt;
+    /// diff.store(&mut arr);
+    ///
+    /// for value in arr.iter() {
+    ///     assert!(value.abs() < 0.002);
+    /// }
+    ///
+    /// let edges = Vec4f::new(0.0, f32::INFINITY, -1.0, f32::NAN).rsqrt_bittrick();
+    /// assert_eq!(edges[0], f32::INFINITY);
+    /// assert_eq!(edges[1], 0.0);
+    /// assert!(edges[2].is_nan() && edges[3].is_nan());
+    /// ```
+    pub fn rsqrt_bittrick(self) -> Self {
+        // Subnormals are scaled by 2^24 to become normal, the result is scaled back by 2^12
+        // SAFETY: sse
+        let tiny: __m128 = unsafe { _mm_cmplt_ps(self.xmm, _mm_set1_ps(f32::MIN_POSITIVE)) };
+        let scaled = Vec4f {
+            xmm: selectf(tiny, (self * Vec4f::from_scalar(16777216.0)).xmm, self.xmm),
+        };
+        // SAFETY: sse2
+        let guess = Vec4f {
+            xmm: unsafe {
+                let bits: __m128i = _mm_castps_si128(scaled.xmm);
+                _mm_castsi128_ps(_mm_sub_epi32(
+                    _mm_set1_epi32(0x5f3759df),
+                    _mm_srli_epi32(bits, 1),
+                ))
+            },
+        };
+        let half = scaled * Vec4f::from_scalar(0.5);
+        let answer = guess * (Vec4f::from_scalar(1.5) - half * guess.squared());
+        // SAFETY: sse
+        unsafe {
+            let answer: __m128 =
+                selectf(tiny, (answer * Vec4f::from_scalar(4096.0)).xmm, answer.xmm);
+            let zero: __m128 = _mm_setzero_ps();
+            let signed_infinity: __m128 = _mm_or_ps(
+                _mm_and_ps(self.xmm, _mm_set1_ps(-0.0)),
+                _mm_set1_ps(f32::INFINITY),
+            );
+            let answer: __m128 = selectf(_mm_cmpeq_ps(self.xmm, zero), signed_infinity, answer);
+            let answer: __m128 =
+                _mm_andnot_ps(_mm_cmpeq_ps(self.xmm, _mm_set1_ps(f32::INFINITY)), answer);
+            Self {
+                xmm: selectf(_mm_cmpnge_ps(self.xmm, zero), Self::nan_vec().xmm, answer),
+            }
+        }
+    }
+
+    /// Returns differences between neighbouring values of the vector, i.e.
+    /// `[x1 - x0, x2 - x1, x3 - x2, 0]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 3.0, 6.0, 10.0);
+    /// assert_eq!(vec.diff(), [2.0, 3.0, 4.0, 0.0]);
+    /// ```
+    pub fn diff(self) -> Self {
+        let next = Vec4f {
+            xmm: shift_down(self.xmm),
+        };
+        (next - self).cutoff(3)
+    }
+
+    /// Returns `true` if bit patterns of vector values are equal to bit patterns of `arr` values.
+    /// Unlike `==`, distinguishes `0.0` from `-0.0` and treats identical NaNs as equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(0.0, -0.0, f32::NAN, 1.0);
+    /// assert!(vec.bit_eq_array(&[0.0, -0.0, f32::NAN, 1.0]));
+    /// assert!(!vec.bit_eq_array(&[0.0, 0.0, f32::NAN, 1.0]));
+    /// ```
+    pub fn bit_eq_array(self, arr: &[f32; 4]) -> bool {
+        // SAFETY: sse2
+        let equal: i32 = unsafe {
+            _mm_movemask_epi8(_mm_cmpeq_epi32(
+                _mm_castps_si128(self.xmm),
+                _mm_castps_si128(_mm_loadu_ps(arr.as_ptr())),
+            ))
+        };
+        equal == 0xFFFF
+    }
+
+    /// Calculates the sum of squared differences between the vector and `target`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let target = Vec4f::new(1.0, 0.0, 4.0, 4.0);
+    /// assert_eq!(vec.sse(target), 5.0);
+    /// ```
+    pub fn sse(self, target: Vec4f) -> f32 {
+        (self - target).squared().horizontal_add()
+    }
+
+    /// Calculates the mean of squared differences between the vector and `target`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let target = Vec4f::new(1.0, 0.0, 4.0, 4.0);
+    /// assert_eq!(vec.mse(target), 1.25);
+    /// ```
+    pub fn mse(self, target: Vec4f) -> f32 {
+        self.sse(target) * 0.25
+    }
+
+    /// Returns index of the first value that is greater than or equal to `threshold`, or `None`
+    /// if there is no such value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 5.0, 3.0, 7.0);
+    /// assert_eq!(vec.first_ge(3.0), Some(1));
+    /// assert_eq!(vec.first_ge(10.0), None);
+    /// ```
+    pub fn first_ge(self, threshold: f32) -> Option<usize> {
+        // SAFETY: sse
+        let mask: i32 = unsafe { _mm_movemask_ps(_mm_cmpge_ps(self.xmm, _mm_set1_ps(threshold))) };
+        if mask == 0 {
+            return None;
+        }
+        Some(mask.trailing_zeros() as usize)
+    }
+
+    /// Treats the vector as `[r, g, b, a]` color and multiplies `r`, `g` and `b` by `a`. Alpha
+    /// value is left unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let color = Vec4f::new(0.5, 0.5, 0.5, 0.5);
+    /// assert_eq!(color.premultiply_alpha(), [0.25, 0.25, 0.25, 0.5]);
+    /// ```
+    pub fn premultiply_alpha(self) -> Self {
+        // SAFETY: sse
+        let alpha: __m128 = unsafe { _mm_shuffle_ps(self.xmm, self.xmm, 0xFF) };
+        let rgb = Vec4fb::new(true, true, true, false);
+        self * Vec4f::select_or(rgb, Vec4f { xmm: alpha }, 1.0)
+    }
+
+    /// Treats the vector as `[r, g, b, a]` color with premultiplied alpha and divides `r`, `g`
+    /// and `b` by `a`. Alpha value is left unchanged. If `a` is `0.0`, color values are set to
+    /// `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let color = Vec4f::new(0.25, 0.25, 0.25, 0.5);
+    /// assert_eq!(color.unpremultiply_alpha(), [0.5, 0.5, 0.5, 0.5]);
+    /// ```
+    pub fn unpremultiply_alpha(self) -> Self {
+        // SAFETY: sse
+        let alpha: __m128 = unsafe { _mm_shuffle_ps(self.xmm, self.xmm, 0xFF) };
+        let rgb = Vec4fb::new(true, true, true, false);
+        self.div_safe(Vec4f::select_or(rgb, Vec4f { xmm: alpha }, 1.0), 0.0)
+    }
+
+    /// Treats the vector as `[r, g, b, a]` color and returns it's relative luminance
+    /// `0.2126 * r + 0.7152 * g + 0.0722 * b` (Rec. 709). Alpha value is ignored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let white = Vec4f::new(1.0, 1.0, 1.0, 0.5);
+    /// assert!((white.luminance() - 1.0).abs() < 0.00001);
+    /// ```
+    pub fn luminance(self) -> f32 {
+        self.weighted_sum(Vec4f::new(0.2126, 0.7152, 0.0722, 0.0))
+    }
+
+    /// Adds every value of the vector to `bins[indices[i]]`. Values are added in lane order, so
+    /// repeated indices accumulate all corresponding values
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `indices` is out of bounds of `bins`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let mut bins = [0.0f32; 3];
+    /// Vec4f::new(1.0, 2.0, 3.0, 4.0).scatter_add(&mut bins, [0, 2, 0, 1]);
+    /// assert_eq!(bins, [4.0, 4.0, 2.0]);
+    /// ```
+    pub fn scatter_add(self, bins: &mut [f32], indices: [usize; 4]) {
+        if indices.iter().any(|&index| index >= bins.len()) {
+            panic!("Index out of bounds");
+        }
+        let mut values = [0.0f32; 4];
+        self.store(&mut values);
+        for (&index, value) in indices.iter().zip(values.iter()) {
+            bins[index] += value;
+        }
+    }
+
+    /// Returns a vector that contains values of `previous` where `mask` is `true` and values of
+    /// the vector elsewhere
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4fb};
+    ///
+    /// let current = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let previous = Vec4f::new(-1.0, -2.0, -3.0, -4.0);
+    /// let mask = Vec4fb::new(false, true, true, false);
+    /// assert_eq!(current.hold_where(previous, mask), [1.0, -2.0, -3.0, 4.0]);
+    /// ```
+    pub fn hold_where(self, previous: Vec4f, mask: Vec4fb) -> Self {
+        Self {
+            xmm: selectf(mask.xmm, previous.xmm, self.xmm),
+        }
+    }
+
+    /// Processes one sample through a biquad filter in direct form I and returns the output
+    /// `y = x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2`. `state` holds `[x1, x2, y1, y2]` (two
+    /// previous inputs and outputs) and is updated in place. Filter must be normalized so that
+    /// `a0 = 1` and `b0 = 1`, `coeffs` contains `[b1, b2, a1, a2]`. A filter with other nonzero
+    /// `b0` is processed by passing `b1 / b0`, `b2 / b0` and scaling every input by `b0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// // y[n] = x[n] + 0.5 * y[n - 1]
+    /// let coeffs = Vec4f::new(0.0, 0.0, -0.5, 0.0);
+    /// let mut state = [0.0f32; 4];
+    /// assert_eq!(Vec4f::biquad_step(&mut state, 1.0, coeffs), 1.0);
+    /// assert_eq!(Vec4f::biquad_step(&mut state, 0.0, coeffs), 0.5);
+    /// assert_eq!(Vec4f::biquad_step(&mut state, 0.0, coeffs), 0.25);
+    /// assert_eq!(state, [0.0, 0.0, 0.25, 0.5]);
+    /// ```
+    pub fn biquad_step(state: &mut [f32; 4], input: f32, coeffs: Vec4f) -> f32 {
+        let history = Vec4f::from(*state);
+        let feedback = history.weighted_sum(coeffs.change_sign::<false, false, true, true>());
+        let output = input + feedback;
+        *state = [input, state[0], output, state[2]];
+        output
+    }
+
+    /// Evaluates cubic Bezier curve with control points `p0`, `p1`, `p2` and `p3` at every
+    /// parameter value of `t`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let t = Vec4f::new(0.0, 0.5, 1.0, 0.25);
+    /// let curve = Vec4f::cubic_bezier(0.0, 1.0, 2.0, 3.0, t);
+    /// assert_eq!(curve, [0.0, 1.5, 3.0, 0.75]);
+    /// ```
+    pub fn cubic_bezier(p0: f32, p1: f32, p2: f32, p3: f32, t: Vec4f) -> Vec4f {
+        let u = Vec4f::from_scalar(1.0) - t;
+        let three = Vec4f::from_scalar(3.0);
+        u.cubed() * Vec4f::from_scalar(p0)
+            + three * u.squared() * t * Vec4f::from_scalar(p1)
+            + three * u * t.squared() * Vec4f::from_scalar(p2)
+            + t.cubed() * Vec4f::from_scalar(p3)
+    }
+
+    /// Evaluates uniform Catmull-Rom spline segment between `p1` and `p2` at every parameter
+    /// value of `t`. `p0` and `p3` are the neighbouring control points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let t = Vec4f::new(0.0, 0.5, 1.0, 0.25);
+    /// let curve = Vec4f::catmull_rom(0.0, 1.0, 2.0, 3.0, t);
+    /// assert_eq!(curve, [1.0, 1.5, 2.0, 1.25]);
+    /// ```
+    pub fn catmull_rom(p0: f32, p1: f32, p2: f32, p3: f32, t: Vec4f) -> Vec4f {
+        let c0 = Vec4f::from_scalar(2.0 * p1);
+        let c1 = Vec4f::from_scalar(p2 - p0);
+        let c2 = Vec4f::from_scalar(2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3);
+        let c3 = Vec4f::from_scalar(3.0 * (p1 - p2) + p3 - p0);
+        (((c3 * t + c2) * t + c1) * t + c0) * Vec4f::from_scalar(0.5)
+    }
+
+    /// Replaces values with absolute value less than `threshold` by `0.0` and returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(0.001, -2.0, -0.0005, 0.5);
+    /// assert_eq!(vec.denoise(0.01), [0.0, -2.0, 0.0, 0.5]);
+    /// ```
+    pub fn denoise(self, threshold: f32) -> Self {
+        // SAFETY: sse
+        let small: __m128 = unsafe { _mm_cmplt_ps(self.abs().xmm, _mm_set1_ps(threshold)) };
+        Self {
+            xmm: selectf(small, Vec4f::default().xmm, self.xmm),
+        }
+    }
+
+    /// Soft thresholding (shrinkage) operator, moves every value towards zero by `lambda` and
+    /// replaces values with absolute value less than `lambda` by zero, i.e.
+    /// `sign(x) * max(|x| - lambda, 0)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(3.0, -2.0, 0.5, -0.25);
+    /// assert_eq!(vec.soft_threshold(1.0), [2.0, -1.0, 0.0, 0.0]);
+    /// ```
+    pub fn soft_threshold(self, lambda: f32) -> Self {
+        let shrunk = (self.abs() - Vec4f::from_scalar(lambda)).relu();
+        shrunk | (self & Vec4f::from_scalar(-0.0f32))
+    }
+
+    /// Peak hold with release, returns `max(self, previous * decay)` for every value, useful for
+    /// envelope followers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let previous = Vec4f::new(1.0, 2.0, 4.0, 0.0);
+    /// let vec = Vec4f::new(0.25, 1.5, 3.0, 1.0);
+    /// assert_eq!(vec.decay_max(previous, 0.5), [0.5, 1.5, 3.0, 1.0]);
+    /// ```
+    pub fn decay_max(self, previous: Vec4f, decay: f32) -> Self {
+        Vec4f::max(self, previous * Vec4f::from_scalar(decay))
+    }
+
+    /// Clamps every value of the vector to `[-limit, limit]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-3.0, -0.5, 0.5, 3.0);
+    /// assert_eq!(vec.clamp_symmetric(1.0), [-1.0, -0.5, 0.5, 1.0]);
+    /// ```
+    pub fn clamp_symmetric(self, limit: f32) -> Self {
+        self.clamp_scalar(-limit, limit)
+    }
+
+    /// Computes the global L2 norm over all values of `data` and, if it exceeds `max_norm`, scales
+    /// every vector in place by `max_norm / norm` so the global norm becomes `max_norm`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let mut data = [Vec4f::new(3.0, 0.0, 0.0, 0.0), Vec4f::new(0.0, 4.0, 0.0, 0.0)];
+    /// Vec4f::clip_by_norm(&mut data, 1.0);
+    /// assert_eq!(data, [[0.6, 0.0, 0.0, 0.0], [0.0, 0.8, 0.0, 0.0]]);
+    /// ```
+    pub fn clip_by_norm(data: &mut [Vec4f], max_norm: f32) {
+        let mut accumulator = DotAccumulator::new();
+        data.iter()
+            .for_each(|vec| accumulator.accumulate(*vec, *vec));
+        let norm: f32 = Vec4f::from_scalar(accumulator.finish()).sqrt()[0];
+        if norm > max_norm {
+            let scale = Vec4f::from_scalar(max_norm / norm);
+            data.iter_mut().for_each(|vec| *vec *= scale);
+        }
+    }
+
+    /// Applies the diagonal matrix with `diagonal` values on its main diagonal to the vector,
+    /// which is the same as multiplying the vectors elementwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let diagonal = Vec4f::new(2.0, 0.5, -1.0, 0.0);
+    /// assert_eq!(vec.scale_by_diagonal(diagonal), [2.0, 1.0, -3.0, 0.0]);
+    /// ```
+    pub fn scale_by_diagonal(self, diagonal: Vec4f) -> Vec4f {
+        self * diagonal
+    }
+
+    /// Treats the vector as two 2D points `[x0, y0, x1, y1]` and rotates both of them by the
+    /// angle with cosine `cos_theta` and sine `sin_theta`, returns the rotated points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let points = Vec4f::new(1.0, 0.0, 0.0, 2.0);
+    /// assert_eq!(points.rotate2d(0.0, 1.0), [0.0, 1.0, -2.0, 0.0]);
+    /// ```
+    pub fn rotate2d(self, cos_theta: f32, sin_theta: f32) -> Vec4f {
+        let sin = Vec4f::new(-sin_theta, sin_theta, -sin_theta, sin_theta);
+        self * Vec4f::from_scalar(cos_theta) + self.swap_pairs() * sin
+    }
+
+    /// Returns a mask which lanes are `true` where values are positive or negative infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY);
+    /// assert_eq!(vec.is_infinite(), [false, false, true, true]);
+    /// ```
+    pub fn is_infinite(self) -> Vec4fb {
+        // SAFETY: sse2
+        unsafe {
+            let magnitude: __m128i =
+                _mm_and_si128(_mm_castps_si128(self.xmm), _mm_set1_epi32(0x7FFF_FFFF));
+            Vec4fb {
+                xmm: _mm_castsi128_ps(_mm_cmpeq_epi32(magnitude, _mm_set1_epi32(0x7F80_0000))),
+            }
+        }
+    }
+
+    /// Returns `true` if any value of the vector is positive or negative infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::from_scalar(f32::MAX);
+    /// assert!(!vec.has_overflow());
+    /// assert!((vec * Vec4f::from_scalar(2.0)).has_overflow());
+    /// ```
+    pub fn has_overflow(self) -> bool {
+        self.is_infinite().any()
+    }
+
+    /// Adds `increment` to the phase and wraps every value back into `[0, 1)` by subtracting its
+    /// floor, returns the new phase
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let phase = Vec4f::new(0.25, 0.75, 0.5, 0.0);
+    /// let increment = Vec4f::new(0.5, 0.5, -0.75, 2.25);
+    /// assert_eq!(phase.phase_accumulate(increment), [0.75, 0.25, 0.75, 0.25]);
+    /// ```
+    pub fn phase_accumulate(self, increment: Vec4f) -> Self {
+        let sum = self + increment;
+        let wrapped = sum - sum.floor();
+        // tiny negative sums are rounded up to exactly 1.0 by the subtraction
+        // SAFETY: sse
+        Self {
+            xmm: unsafe { _mm_andnot_ps(_mm_cmpge_ps(wrapped.xmm, _mm_set1_ps(1.0)), wrapped.xmm) },
+        }
+    }
+
+    /// Calculates the centroid of four points, i.e. the average of `a`, `b`, `c` and `d` for
+    /// every index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(0.0, 0.0, 0.0, 1.0);
+    /// let b = Vec4f::new(4.0, 0.0, 0.0, 1.0);
+    /// let c = Vec4f::new(0.0, 4.0, 0.0, 1.0);
+    /// let d = Vec4f::new(0.0, 0.0, 4.0, 1.0);
+    /// assert_eq!(Vec4f::centroid4(a, b, c, d), [1.0, 1.0, 1.0, 1.0]);
+    /// ```
+    pub fn centroid4(a: Vec4f, b: Vec4f, c: Vec4f, d: Vec4f) -> Vec4f {
+        ((a + b) + (c + d)) * Vec4f::from_scalar(0.25)
+    }
+
+    /// Radix-2 FFT butterfly over two pairs of interleaved complex numbers
+    /// `[re0, im0, re1, im1]`, returns `(a + w * b, a - w * b)` where `w * b` is the complex
+    /// product of `twiddle` and `b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 0.0, 2.0, 1.0);
+    /// let b = Vec4f::new(0.0, 1.0, 1.0, 1.0);
+    /// let twiddle = Vec4f::new(1.0, 0.0, 0.0, -1.0);
+    /// let (sum, difference) = Vec4f::butterfly(a, b, twiddle);
+    /// assert_eq!(sum, [1.0, 1.0, 3.0, 0.0]);
+    /// assert_eq!(difference, [1.0, -1.0, 1.0, 2.0]);
+    /// ```
+    pub fn butterfly(a: Vec4f, b: Vec4f, twiddle: Vec4f) -> (Vec4f, Vec4f) {
+        let product = Vec4f {
+            xmm: complex_mul(twiddle.xmm, b.xmm),
+        };
+        (a + product, a - product)
+    }
+
+    /// Counts how many times the sign bit changes between adjacent values of the vector, useful
+    /// for zero-crossing detection. `-0.0` is treated as negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, -2.0, -3.0, 4.0);
+    /// assert_eq!(vec.sign_changes(), 2);
+    /// ```
+    pub fn sign_changes(self) -> u32 {
+        // SAFETY: sse
+        let signs: i32 = unsafe { _mm_movemask_ps(self.xmm) };
+        ((signs ^ (signs >> 1)) & 0b111).count_ones()
+    }
+
+    /// Rounds every value to the nearest integer (halves are rounded to even) and converts it to
+    /// `i32`. Values out of `i32` range and NaN are converted to `i32::MIN` (`0x80000000`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.4, -1.6, 2.5, 3.5);
+    /// assert_eq!(vec.round_to_int(), [1, -2, 2, 4]);
+    /// ```
+    pub fn round_to_int(self) -> Vec4i {
+        Vec4i {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_cvtps_epi32(self.xmm) },
+        }
+    }
+
+    /// Truncates every value towards zero and converts it to `i32`. Values out of `i32` range
+    /// and NaN are converted to `i32::MIN` (`0x80000000`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.9, -1.9, 2.5, -0.5);
+    /// assert_eq!(vec.truncate_to_int(), [1, -1, 2, 0]);
+    /// ```
+    pub fn truncate_to_int(self) -> Vec4i {
+        Vec4i {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_cvttps_epi32(self.xmm) },
+        }
+    }
+
+    /// Crossfades between two signals with the fade factor `t`, returns `a * (1 - t) + b * t`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4f::new(3.0, 2.0, 1.0, 0.0);
+    /// assert_eq!(Vec4f::crossfade(a, b, 0.25), [1.5, 2.0, 2.5, 3.0]);
+    /// ```
+    pub fn crossfade(a: Vec4f, b: Vec4f, t: f32) -> Vec4f {
+        a * Vec4f::from_scalar(1.0 - t) + b * Vec4f::from_scalar(t)
+    }
+
+    /// Chooses values from `a` where `mask` lanes are `true` (all bits set) and from `b` where
+    /// they are `false` (all bits cleared), returns the result. Every lane of `mask` must have
+    /// either all bits set or all bits cleared, e.g. masks returned by `cmp_*` methods
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let a = Vec4f::new(1.0, 5.0, 3.0, 7.0);
+    /// let b = Vec4f::from_scalar(4.0);
+    /// assert_eq!(Vec4f::select(a.cmp_lt(b), a, b), [1.0, 4.0, 3.0, 4.0]);
+    /// ```
+    pub fn select(mask: Vec4f, a: Vec4f, b: Vec4f) -> Vec4f {
+        Vec4f {
+            xmm: selectf(mask.xmm, a.xmm, b.xmm),
+        }
+    }
+
+    /// Treats the vector as two stereo frames `[L0, R0, L1, R1]` and multiplies left channel
+    /// values by `left_gain` and right channel values by `right_gain`, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let frames = Vec4f::new(1.0, 1.0, -0.5, 0.5);
+    /// assert_eq!(frames.apply_stereo_gain(0.5, 2.0), [0.5, 2.0, -0.25, 1.0]);
+    /// ```
+    pub fn apply_stereo_gain(self, left_gain: f32, right_gain: f32) -> Vec4f {
+        self * Vec4f::alternating(left_gain, right_gain)
+    }
+
+    /// One-pole smoothing step (leaky integrator), moves `previous` towards the vector by the
+    /// fraction `coeff`, i.e. returns `previous + coeff * (self - previous)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let target = Vec4f::new(1.0, 2.0, -4.0, 0.0);
+    /// let previous = Vec4f::default();
+    /// assert_eq!(target.one_pole(previous, 0.5), [0.5, 1.0, -2.0, 0.0]);
+    /// ```
+    pub fn one_pole(self, previous: Vec4f, coeff: f32) -> Self {
+        previous + Vec4f::from_scalar(coeff) * (self - previous)
+    }
+
+    /// Clamps every value to `[0, 1]` and quantizes it to the closest of `levels` evenly spaced
+    /// steps, i.e. `round(self * (levels - 1)) / (levels - 1)`. Halfway values are rounded to
+    /// the even step like in `round`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `levels` is less than `2`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(-0.5, 0.3, 0.6, 1.5);
+    /// assert_eq!(vec.snap_to_levels(3), [0.0, 0.5, 0.5, 1.0]);
+    /// ```
+    pub fn snap_to_levels(self, levels: u32) -> Self {
+        if levels < 2 {
+            panic!("Number of levels must be at least 2");
+        }
+        let steps = Vec4f::from_scalar((levels - 1) as f32);
+        (self.clamp_scalar(0.0, 1.0) * steps).round() / steps
+    }
+
+    /// Returns the reciprocal of the sum of all vector values, i.e. `1.0 / horizontal_add()`.
+    /// Returns infinity with the sign of the sum if the sum is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let weights = Vec4f::new(1.0, 2.0, 3.0, 2.0);
+    /// assert_eq!(weights.horizontal_add_recip(), 0.125);
+    /// ```
+    pub fn horizontal_add_recip(self) -> f32 {
+        1.0 / self.horizontal_add()
+    }
+
+    /// Converts values to fixed point with `scale` steps per unit, i.e. returns
+    /// `(self * scale).round_to_int()`. Values out of `i32` range are converted to `i32::MIN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, -0.5, 0.3, 0.0);
+    /// assert_eq!(vec.quantize(256.0), [256, -128, 77, 0]);
+    /// ```
+    pub fn quantize(self, scale: f32) -> Vec4i {
+        (self * Vec4f::from_scalar(scale)).round_to_int()
+    }
+
+    /// Returns the unbiased base-2 exponent of every value, like `ilogb`, so that
+    /// `2^exponent <= |x| < 2^(exponent + 1)`. Subnormal values get their real exponent (down to
+    /// `-149`). Zero and NaN give `i32::MIN`, infinity gives `i32::MAX`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 2.0, 0.5, -12.0);
+    /// assert_eq!(vec.exponent(), [0, 1, -1, 3]);
+    /// ```
+    pub fn exponent(self) -> Vec4i {
+        let (bits, shift) = normalized_bits(self.xmm);
+        // SAFETY: sse2
+        unsafe {
+            let biased: __m128i = _mm_and_si128(_mm_srli_epi32(bits, 23), _mm_set1_epi32(0xFF));
+            let exponent: __m128i =
+                _mm_sub_epi32(_mm_sub_epi32(biased, _mm_set1_epi32(127)), shift);
+            let magnitude: __m128i =
+                _mm_and_si128(_mm_castps_si128(self.xmm), _mm_set1_epi32(0x7FFF_FFFF));
+            let special: __m128i = _mm_or_si128(
+                _mm_cmpeq_epi32(magnitude, _mm_setzero_si128()),
+                _mm_cmpgt_epi32(magnitude, _mm_set1_epi32(0x7F7F_FFFF)),
+            );
+            // i32::MAX for infinity, i32::MIN for zero and NaN
+            let infinite: __m128i = _mm_cmpeq_epi32(magnitude, _mm_set1_epi32(0x7F80_0000));
+            let special_value: __m128i = _mm_xor_si128(_mm_set1_epi32(i32::MIN), infinite);
+            Vec4i {
+                xmm: _mm_or_si128(
+                    _mm_and_si128(special, special_value),
+                    _mm_andnot_si128(special, exponent),
+                ),
+            }
+        }
+    }
+
+    /// Returns the normalized significand of every value: a value in `[1, 2)` with the sign of
+    /// the original value, so that `x == x.mantissa() * 2^x.exponent()`. Subnormal values are
+    /// normalized as well. Zero, infinity and NaN are returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4f;
+    ///
+    /// let vec = Vec4f::new(1.0, 3.0, 0.375, -12.0);
+    /// assert_eq!(vec.mantissa(), [1.0, 1.5, 1.5, -1.5]);
+    /// ```
+    pub fn mantissa(self) -> Vec4f {
+        let (bits, _) = normalized_bits(self.xmm);
+        // SAFETY: sse2
+        unsafe {
+            let mantissa: __m128i = _mm_or_si128(
+                _mm_and_si128(bits, _mm_set1_epi32(0x807F_FFFFu32 as i32)),
+                _mm_set1_epi32(0x3F80_0000),
+            );
+            let magnitude: __m128i =
+                _mm_and_si128(_mm_castps_si128(self.xmm), _mm_set1_epi32(0x7FFF_FFFF));
+            let special: __m128 = _mm_castsi128_ps(_mm_or_si128(
+                _mm_cmpeq_epi32(magnitude, _mm_setzero_si128()),
+                _mm_cmpgt_epi32(magnitude, _mm_set1_epi32(0x7F7F_FFFF)),
+            ));
+            Vec4f {
+                xmm: selectf(special, self.xmm, _mm_castsi128_ps(mantissa)),
+            }
+        }
+    }
+
+    /// Raises every value of the vector to the integer power from the same lane of `exponents`
+    /// using exponentiation by squaring and returns the result. Negative exponents give the
+    /// reciprocal of the positive power (so zero raised to a negative power is infinity), zero
+    /// exponent gives `1.0` for any value. Exponent `i32::MIN` gives NaN, same as `pow`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Vec4f, Vec4i};
+    ///
+    /// let vec = Vec4f::new(3.0, 2.0, 4.0, 7.0);
+    /// let exponents = Vec4i::new(2, 3, -1, 0);
+    /// assert_eq!(vec.pow_lanes(exponents), [9.0, 8.0, 0.25, 1.0]);
+    /// ```
+    pub fn pow_lanes(self, exponents: Vec4i) -> Self {
+        // SAFETY: sse2
+        unsafe {
+            let sign: __m128i = _mm_srai_epi32(exponents.xmm, 31);
+            // |n| for every lane, i32::MIN becomes 2^31 when treated as unsigned
+            let mut remaining: __m128i = _mm_sub_epi32(_mm_xor_si128(exponents.xmm, sign), sign);
+            let one: __m128i = _mm_set1_epi32(1);
+            let mut answer = Vec4f::from_scalar(1.0);
+            let mut power = self;
+            while _mm_movemask_epi8(_mm_cmpeq_epi32(remaining, _mm_setzero_si128())) != 0xFFFF {
+                let odd: __m128 =
+                    _mm_castsi128_ps(_mm_cmpeq_epi32(_mm_and_si128(remaining, one), one));
+                answer.xmm = selectf(odd, (answer * power).xmm, answer.xmm);
+                remaining = _mm_srli_epi32(remaining, 1);
+                power *= power;
+            }
+            let answer: __m128 = selectf(
+                _mm_castsi128_ps(sign),
+                (Vec4f::from_scalar(1.0) / answer).xmm,
+                answer.xmm,
+            );
+            let min: __m128 =
+                _mm_castsi128_ps(_mm_cmpeq_epi32(exponents.xmm, _mm_set1_epi32(i32::MIN)));
+            Self {
+                xmm: selectf(min, Self::nan_vec().xmm, answer),
+            }
+        }
+    }
+}
+
+/// Constructs vector from array
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4f;
+///
+/// let vec = Vec4f::from([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(vec, [1.0, 2.0, 3.0, 4.0]);
+/// ```
+impl core::convert::From<[f32; 4]> for Vec4f {
+    fn from(value: [f32; 4]) -> Self {
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_loadu_ps(value.as_ptr()) },
+        }
+    }
+}
+
+/// Constructs vector from slice
+///
+/// # Panics
+///
+/// Panics if slice size is less than `4`
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4f;
+///
+/// let arr = vec![-1.0, 2.0, 3.0, 1.5];
+/// let vec = Vec4f::from(arr.as_slice());
+/// assert_eq!(vec, [-1.0, 2.0, 3.0, 1.5]);
+/// ```
+impl core::convert::From<&[f32]> for Vec4f {
+    fn from(value: &[f32]) -> Self {
+        if value.len() < 4 {
+            panic!("Slice size is not enough to construct a vector");
+        }
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_loadu_ps(value.as_ptr()) },
+        }
+    }
+}
+
+/// Creates vector initialized with `0.0` values
+///
+/// # Examples
+///
+/// ```
 /// use vcl_rust::Vec4f;
 ///
 /// let vec = Vec4f::default();
@@ -1151,3 +3491,367 @@ impl core::fmt::Debug for Vec4f {
         arr.fmt(f)
     }
 }
+
+/// Accumulator for long dot products. Keeps several independent partial sums so consecutive
+/// `accumulate` calls don't depend on each other
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::{DotAccumulator, Vec4f};
+///
+/// let mut acc = DotAccumulator::new();
+/// acc.accumulate(Vec4f::new(1.0, 2.0, 3.0, 4.0), Vec4f::from_scalar(2.0));
+/// acc.accumulate(Vec4f::new(1.0, 1.0, 1.0, 1.0), Vec4f::new(1.0, 2.0, 3.0, 4.0));
+/// assert_eq!(acc.finish(), 30.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DotAccumulator {
+    sums: [Vec4f; 4],
+    next: usize,
+}
+
+impl DotAccumulator {
+    /// Returns an accumulator with all partial sums equal to `0.0`
+    pub fn new() -> Self {
+        Self {
+            sums: [Vec4f::default(); 4],
+            next: 0,
+        }
+    }
+
+    /// Adds products of `a` and `b` values to the next partial sum
+    pub fn accumulate(&mut self, a: Vec4f, b: Vec4f) {
+        self.sums[self.next] += a * b;
+        self.next = (self.next + 1) & 3;
+    }
+
+    /// Combines all partial sums and returns the dot product
+    pub fn finish(self) -> f32 {
+        let [a, b, c, d] = self.sums;
+        ((a + b) + (c + d)).horizontal_add()
+    }
+}
+
+/// Creates an empty accumulator, same as `DotAccumulator::new`
+impl core::default::Default for DotAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Boolean vector of four values that is used as a mask for `Vec4f`. Every `true` lane has all
+/// bits set and every `false` lane has all bits cleared
+#[derive(Clone, Copy)]
+pub struct Vec4fb {
+    xmm: __m128,
+}
+
+impl Vec4fb {
+    /// Returns `Vec4fb` that contains four `bool` values that are equal to the arguments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// let mask = Vec4fb::new(true, false, false, true);
+    /// assert_eq!(mask, [true, false, false, true]);
+    /// ```
+    pub fn new(a: bool, b: bool, c: bool, d: bool) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe {
+                _mm_castsi128_ps(_mm_setr_epi32(
+                    -(a as i32),
+                    -(b as i32),
+                    -(c as i32),
+                    -(d as i32),
+                ))
+            },
+        }
+    }
+
+    /// Returns `Vec4fb` built from an array of `bool` values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// let mask = Vec4fb::from_array([false, true, true, false]);
+    /// assert_eq!(mask, Vec4fb::new(false, true, true, false));
+    /// ```
+    pub fn from_array(bools: [bool; 4]) -> Self {
+        let [a, b, c, d] = bools;
+        Self::new(a, b, c, d)
+    }
+
+    /// Converts mask to an array of `bool` values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// let mask = Vec4fb::new(false, true, true, false);
+    /// assert_eq!(mask.to_array(), [false, true, true, false]);
+    /// ```
+    pub fn to_array(self) -> [bool; 4] {
+        let bits = self.to_bits();
+        [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0]
+    }
+
+    /// Returns a bitmask where bit `i` is set if lane `i` is `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// let mask = Vec4fb::new(true, false, true, true);
+    /// assert_eq!(mask.to_bits(), 0b1101);
+    /// ```
+    pub fn to_bits(self) -> u8 {
+        // SAFETY: sse
+        unsafe { _mm_movemask_ps(self.xmm) as u8 }
+    }
+
+    /// Returns `true` if at least one lane is `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// assert!(Vec4fb::new(false, false, true, false).any());
+    /// assert!(!Vec4fb::new(false, false, false, false).any());
+    /// ```
+    pub fn any(self) -> bool {
+        self.to_bits() != 0
+    }
+
+    /// Returns `true` if all lanes are `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4fb;
+    ///
+    /// assert!(Vec4fb::new(true, true, true, true).all());
+    /// assert!(!Vec4fb::new(true, false, true, true).all());
+    /// ```
+    pub fn all(self) -> bool {
+        self.to_bits() == 0x0F
+    }
+}
+
+/// Lane-wise AND of two masks
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let a = Vec4fb::new(true, true, false, false);
+/// let b = Vec4fb::new(true, false, true, false);
+/// assert_eq!(a & b, [true, false, false, false]);
+/// ```
+impl core::ops::BitAnd for Vec4fb {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_and_ps(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Lane-wise OR of two masks
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let a = Vec4fb::new(true, true, false, false);
+/// let b = Vec4fb::new(true, false, true, false);
+/// assert_eq!(a | b, [true, true, true, false]);
+/// ```
+impl core::ops::BitOr for Vec4fb {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_or_ps(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Lane-wise XOR of two masks
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let a = Vec4fb::new(true, true, false, false);
+/// let b = Vec4fb::new(true, false, true, false);
+/// assert_eq!(a ^ b, [false, true, true, false]);
+/// ```
+impl core::ops::BitXor for Vec4fb {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse
+            xmm: unsafe { _mm_xor_ps(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Lane-wise NOT of a mask
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let a = Vec4fb::new(true, true, false, false);
+/// assert_eq!(!a, [false, false, true, true]);
+/// ```
+impl core::ops::Not for Vec4fb {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_xor_ps(self.xmm, _mm_castsi128_ps(_mm_set1_epi32(-1))) },
+        }
+    }
+}
+
+/// Comparison
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let a = Vec4fb::new(true, false, true, false);
+/// assert_eq!(a, Vec4fb::new(true, false, true, false));
+/// assert_ne!(a, Vec4fb::new(true, true, true, false));
+/// ```
+impl core::cmp::PartialEq for Vec4fb {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+/// Operator ==, compares mask to [bool; 4]
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let mask = Vec4fb::new(true, false, true, false);
+/// assert_eq!(mask, [true, false, true, false]);
+/// ```
+impl core::cmp::PartialEq<[bool; 4]> for Vec4fb {
+    fn eq(&self, other: &[bool; 4]) -> bool {
+        self.eq(&Vec4fb::from_array(*other))
+    }
+}
+
+/// Formats mask as `[bool; 4]` debug string
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4fb;
+///
+/// let mask = Vec4fb::new(true, false, true, false);
+/// println!("{:?}", mask);
+/// ```
+impl core::fmt::Debug for Vec4fb {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_array().fmt(f)
+    }
+}
+
+/// Wrapper that aligns it's contents by `16` bytes, so they can be used with aligned loads and
+/// stores
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::{Align16, Vec4f};
+///
+/// let arr = Align16([1.0f32, 2.0, 3.0, 4.0]);
+/// let mut vec = Vec4f::default();
+/// vec.load_aligned(&arr.0);
+/// assert_eq!(vec, [1.0, 2.0, 3.0, 4.0]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(align(16))]
+pub struct Align16<T>(pub T);
+
+/// Buffer of four `f32` values aligned by `16` bytes, can be passed directly to `load_aligned`
+/// and `store_aligned`
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::{AlignedBuffer4, Vec4f};
+///
+/// let mut buffer = AlignedBuffer4::default();
+/// Vec4f::new(1.0, 2.0, 3.0, 4.0).store_aligned(buffer.as_mut_slice());
+///
+/// let mut vec = Vec4f::default();
+/// vec.load_aligned(buffer.as_slice());
+/// assert_eq!(vec, [1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub type AlignedBuffer4 = Align16<[f32; 4]>;
+
+impl Align16<[f32; 4]> {
+    /// Returns aligned slice of buffer values
+    pub fn as_slice(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// Returns aligned mutable slice of buffer values
+    pub fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.0
+    }
+}
+
+/// Error returned by `Vec4f::from_csv`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVec4fError {
+    /// The string doesn't contain exactly four comma separated values
+    WrongLength,
+    /// One of the values is not a valid `f32`
+    InvalidValue(core::num::ParseFloatError),
+}
+
+/// Formats error as a human readable message
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4f;
+///
+/// let err = Vec4f::from_csv("1,2").unwrap_err();
+/// println!("{}", err);
+/// ```
+impl core::fmt::Display for ParseVec4fError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseVec4fError::WrongLength => f.write_str("expected four comma separated values"),
+            ParseVec4fError::InvalidValue(err) => write!(f, "invalid value: {}", err),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! This module contains `Vec2d` struct with methods and functions to work with it
+//!
+//! This crate can only be compiled on `x86` or `x86_64` architecture and a proccessor that supports at
+//! least `SSE2` instruction set
+
+use crate::intrinsics::*;
+
+/// Packed array of two `f64` values that can be used for SIMD operations
+#[derive(Clone, Copy)]
+pub struct Vec2d {
+    xmm: __m128d,
+}
+
+impl Vec2d {
+    /// Associated const - size of the packed vector
+    pub const LEN: usize = 2;
+
+    /// Returns `Vec2d` that contains two `f64` values that are equal to the arguments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::new(1.0, 2.0);
+    /// assert_eq!(vec, [1.0, 2.0]);
+    /// ```
+    pub fn new(a: f64, b: f64) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_setr_pd(a, b) },
+        }
+    }
+
+    /// Returns `Vec2d` that contains two values of type `f64` equal to the argument
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::from_scalar(2.0);
+    /// assert_eq!(vec, [2.0f64; 2]);
+    /// ```
+    pub fn from_scalar(value: f64) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_set1_pd(value) },
+        }
+    }
+
+    fn is_aligned(buffer: &[f64]) -> bool {
+        (buffer.as_ptr() as usize) & 0xf == 0
+    }
+
+    /// Copies values of the vector to a mutable slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::new(1.0, 2.0);
+    /// let mut arr = [0.0f64; 2];
+    /// vec.store(&mut arr);
+    /// assert_eq!(arr, [1.0, 2.0]);
+    /// ```
+    pub fn store(self, buffer: &mut [f64]) {
+        if buffer.len() < 2 {
+            panic!("Buffer len not enough to store Vec2d");
+        }
+        // SAFETY: sse2
+        unsafe { _mm_storeu_pd(buffer.as_mut_ptr(), self.xmm) }
+    }
+
+    /// Copies values of the vector to a mutable slice. Address of the slice must be divisible by
+    /// `16`. Is more efficient than `store`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than `2` or the address of the slice is not aligned
+    /// by `16` bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Align16, Vec2d};
+    ///
+    /// let vec = Vec2d::new(1.0, 2.0);
+    /// let mut arr = Align16([0.0f64; 2]);
+    /// vec.store_aligned(&mut arr.0);
+    /// assert_eq!(arr.0, [1.0, 2.0]);
+    /// ```
+    pub fn store_aligned(self, buffer: &mut [f64]) {
+        if buffer.len() < 2 {
+            panic!("Buffer len not enough to store Vec2d");
+        }
+        if !Self::is_aligned(buffer) {
+            panic!("Buffer address is not aligned by 16");
+        }
+        // SAFETY: sse2
+        unsafe { _mm_store_pd(buffer.as_mut_ptr(), self.xmm) }
+    }
+
+    /// Loads values from double slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than `2`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let arr: [f64; 2] = [-2.0, 1.0];
+    /// let mut d = Vec2d::default();
+    /// d.load(&arr);
+    /// assert_eq!(d, [-2.0, 1.0]);
+    /// ```
+    pub fn load(&mut self, buffer: &[f64]) {
+        if buffer.len() < 2 {
+            panic!("Buffer len not enough to load vector");
+        }
+        // SAFETY: sse2
+        self.xmm = unsafe { _mm_loadu_pd(buffer.as_ptr()) };
+    }
+
+    /// Loads values from double slice aligned by `16` bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer.len()` is less than `2` or if it's address is not aligned by `16`
+    /// bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::{Align16, Vec2d};
+    ///
+    /// let arr = Align16([3.0f64, -1.0]);
+    /// let mut vec = Vec2d::default();
+    /// vec.load_aligned(&arr.0);
+    /// assert_eq!(vec, [3.0, -1.0]);
+    /// ```
+    pub fn load_aligned(&mut self, buffer: &[f64]) {
+        if buffer.len() < 2 {
+            panic!("Buffer len not enough to load vector");
+        }
+        if !Self::is_aligned(buffer) {
+            panic!("Buffer address is not aligned by 16");
+        }
+        // SAFETY: sse2
+        self.xmm = unsafe { _mm_load_pd(buffer.as_ptr()) };
+    }
+
+    /// Returns a vector containing square roots of all values of original vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::new(4.0, 2.0);
+    /// assert_eq!(vec.sqrt(), [2.0, f64::sqrt(2.0)]);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_sqrt_pd(self.xmm) },
+        }
+    }
+
+    /// Returns a vector containing absolute values of the original vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::new(-1.0, 2.0);
+    /// assert_eq!(vec.abs(), [1.0, 2.0]);
+    /// ```
+    pub fn abs(self) -> Self {
+        // SAFETY: sse2
+        let mask: __m128d = unsafe { _mm_castsi128_pd(_mm_set1_epi64x(i64::MAX)) };
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_and_pd(self.xmm, mask) },
+        }
+    }
+
+    /// Calculates the sum of both vector values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let vec = Vec2d::new(1.5, 2.0);
+    /// assert_eq!(vec.horizontal_add(), 3.5);
+    /// ```
+    pub fn horizontal_add(self) -> f64 {
+        // SAFETY: sse2
+        unsafe {
+            let high: __m128d = _mm_unpackhi_pd(self.xmm, self.xmm);
+            _mm_cvtsd_f64(_mm_add_sd(self.xmm, high))
+        }
+    }
+
+    /// Chooses maximum for each index from two vectors, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let first = Vec2d::new(5.0, -2.0);
+    /// let second = Vec2d::new(1.0, 2.0);
+    /// assert_eq!(Vec2d::max(first, second), [5.0, 2.0]);
+    /// ```
+    pub fn max(first: Vec2d, second: Vec2d) -> Vec2d {
+        Vec2d {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_max_pd(first.xmm, second.xmm) },
+        }
+    }
+
+    /// Chooses minimum for each index from two vectors, returns the result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec2d;
+    ///
+    /// let first = Vec2d::new(5.0, -2.0);
+    /// let second = Vec2d::new(1.0, 2.0);
+    /// assert_eq!(Vec2d::min(first, second), [1.0, -2.0]);
+    /// ```
+    pub fn min(first: Vec2d, second: Vec2d) -> Vec2d {
+        Vec2d {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_min_pd(first.xmm, second.xmm) },
+        }
+    }
+}
+
+/// Creates vector initialized with `0.0` values
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let vec = Vec2d::default();
+/// assert_eq!(vec, [0.0f64; 2]);
+/// ```
+impl core::default::Default for Vec2d {
+    fn default() -> Self {
+        Self::from_scalar(0.0)
+    }
+}
+
+/// Sum of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let a = Vec2d::new(1.0, 2.0);
+/// let b = Vec2d::new(0.5, -3.0);
+/// assert_eq!(a + b, [1.5, -1.0]);
+/// ```
+impl core::ops::Add for Vec2d {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_add_pd(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Difference of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let a = Vec2d::new(1.0, 2.0);
+/// let b = Vec2d::new(2.0, 1.0);
+/// assert_eq!(a - b, [-1.0, 1.0]);
+/// ```
+impl core::ops::Sub for Vec2d {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_sub_pd(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Product of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let a = Vec2d::new(1.5, 2.0);
+/// let b = Vec2d::new(2.0, -4.0);
+/// assert_eq!(a * b, [3.0, -8.0]);
+/// ```
+impl core::ops::Mul for Vec2d {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_mul_pd(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Quotient of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let a = Vec2d::new(3.0, 2.0);
+/// let b = Vec2d::new(2.0, -4.0);
+/// assert_eq!(a / b, [1.5, -0.5]);
+/// ```
+impl core::ops::Div for Vec2d {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_div_pd(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Operator ==, compares two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let a = Vec2d::new(-1.0, 2.0);
+/// let b = Vec2d::new(-1.0, 2.0);
+/// assert_eq!(a, b);
+///
+/// let c = Vec2d::new(-1.0, 2.5);
+/// assert_ne!(a, c);
+/// ```
+impl core::cmp::PartialEq for Vec2d {
+    fn eq(&self, other: &Self) -> bool {
+        // SAFETY: sse2
+        let comparison: i32 = unsafe { _mm_movemask_pd(_mm_cmpeq_pd(self.xmm, other.xmm)) };
+        comparison == 0x03i32
+    }
+}
+
+/// Operator ==, compares vector to [f64; 2]
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let vec = Vec2d::new(1.0, 2.0);
+/// assert_eq!(vec, [1.0, 2.0]);
+/// ```
+impl core::cmp::PartialEq<[f64; 2]> for Vec2d {
+    fn eq(&self, other: &[f64; 2]) -> bool {
+        self.eq(&Vec2d::new(other[0], other[1]))
+    }
+}
+
+/// Reinterprets vector as `[f64; 2]` and formats it as a debug string
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec2d;
+///
+/// let vec = Vec2d::new(1.0, 2.0);
+/// println!("{:?}", vec);
+/// ```
+impl core::fmt::Debug for Vec2d {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut arr = [0.0f64; 2];
+        self.store(&mut arr);
+        arr.fmt(f)
+    }
+}
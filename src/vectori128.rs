@@ -0,0 +1,384 @@
+//! This module contains `Vec4i` struct with methods and functions to work with it
+//!
+//! This crate can only be compiled on `x86` or `x86_64` architecture and a proccessor that supports at
+//! least `SSE2` instruction set
+
+use crate::intrinsics::*;
+use crate::Vec4f;
+
+/// Packed array of four `i32` values that can be used for SIMD operations
+#[derive(Clone, Copy)]
+pub struct Vec4i {
+    pub(crate) xmm: __m128i,
+}
+
+impl Vec4i {
+    /// Associated const - size of the packed vector
+    pub const LEN: usize = 4;
+
+    /// Returns `Vec4i` that contains four `i32` values that are equal to the arguments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let vec = Vec4i::new(1, 2, 3, 4);
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    pub fn new(a: i32, b: i32, c: i32, d: i32) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_setr_epi32(a, b, c, d) },
+        }
+    }
+
+    /// Returns `Vec4i` that contains four values of type `i32` equal to the argument
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let vec = Vec4i::from_scalar(2);
+    /// assert_eq!(vec, [2; 4]);
+    /// ```
+    pub fn from_scalar(value: i32) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_set1_epi32(value) },
+        }
+    }
+
+    /// Copies values of the vector to a mutable slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than 4
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let vec = Vec4i::new(1, 2, 3, 4);
+    /// let mut arr = [0i32; 4];
+    /// vec.store(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// ```
+    pub fn store(self, buffer: &mut [i32]) {
+        if buffer.len() < 4 {
+            panic!("Buffer len not enough to store Vec4i");
+        }
+        // SAFETY: sse2
+        unsafe { _mm_storeu_si128(buffer.as_mut_ptr().cast(), self.xmm) }
+    }
+
+    /// Loads values from integer slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer.len()` is less than `4`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let arr: [i32; 4] = [-2, 1, 3, -4];
+    /// let mut d = Vec4i::default();
+    /// d.load(&arr);
+    /// assert_eq!(d, [-2, 1, 3, -4]);
+    /// ```
+    pub fn load(&mut self, buffer: &[i32]) {
+        if buffer.len() < 4 {
+            panic!("Buffer len not enough to load vector");
+        }
+        // SAFETY: sse2
+        self.xmm = unsafe { _mm_loadu_si128(buffer.as_ptr().cast()) };
+    }
+
+    /// Converts every value of the vector to `f32`, values with magnitude above `2^24` are rounded
+    /// to the nearest representable `f32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let vec = Vec4i::new(1, -2, 0, 16777217);
+    /// assert_eq!(vec.to_f32(), [1.0, -2.0, 0.0, 16777216.0]);
+    /// ```
+    pub fn to_f32(self) -> Vec4f {
+        Vec4f {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_cvtepi32_ps(self.xmm) },
+        }
+    }
+
+    /// Converts fixed-point values with `scale` steps per unit back to floats, i.e. returns
+    /// `self.to_f32() / scale`. Inverse of `Vec4f::quantize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vcl_rust::Vec4i;
+    ///
+    /// let fixed = Vec4i::new(256, -128, 64, 0);
+    /// assert_eq!(fixed.dequantize(256.0), [1.0, -0.5, 0.25, 0.0]);
+    /// ```
+    pub fn dequantize(self, scale: f32) -> Vec4f {
+        self.to_f32() / Vec4f::from_scalar(scale)
+    }
+}
+
+/// Creates vector initialized with `0` values
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let vec = Vec4i::default();
+/// assert_eq!(vec, [0; 4]);
+/// ```
+impl core::default::Default for Vec4i {
+    fn default() -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_setzero_si128() },
+        }
+    }
+}
+
+/// Sum of two vectors, wraps around on overflow
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(1, 2, 3, i32::MAX);
+/// let b = Vec4i::new(1, -5, 3, 1);
+/// assert_eq!(a + b, [2, -3, 6, i32::MIN]);
+/// ```
+impl core::ops::Add for Vec4i {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_add_epi32(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Difference of two vectors, wraps around on overflow
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(1, 2, 3, i32::MIN);
+/// let b = Vec4i::new(2, 1, 4, 1);
+/// assert_eq!(a - b, [-1, 1, -1, i32::MAX]);
+/// ```
+impl core::ops::Sub for Vec4i {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_sub_epi32(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Product of two vectors, keeps the low `32` bits of every product
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(1, -2, 3, -4);
+/// let b = Vec4i::new(5, 6, -7, -8);
+/// assert_eq!(a * b, [5, -12, -21, 32]);
+/// ```
+impl core::ops::Mul for Vec4i {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        #[cfg(target_feature = "sse4.1")]
+        {
+            Self {
+                // SAFETY: sse4.1
+                xmm: unsafe { _mm_mullo_epi32(self.xmm, other.xmm) },
+            }
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        {
+            // Low 32 bits of the product are the same for signed and unsigned multiplication
+            // SAFETY: sse2
+            unsafe {
+                let even: __m128i = _mm_mul_epu32(self.xmm, other.xmm);
+                let odd: __m128i = _mm_mul_epu32(
+                    _mm_shuffle_epi32(self.xmm, 0xF5),
+                    _mm_shuffle_epi32(other.xmm, 0xF5),
+                );
+                Self {
+                    xmm: _mm_unpacklo_epi32(
+                        _mm_shuffle_epi32(even, 0x08),
+                        _mm_shuffle_epi32(odd, 0x08),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Bitwise and of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(0b1100, -1, 7, 0);
+/// let b = Vec4i::new(0b1010, 5, 0, -1);
+/// assert_eq!(a & b, [0b1000, 5, 0, 0]);
+/// ```
+impl core::ops::BitAnd for Vec4i {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_and_si128(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Bitwise or of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(0b1100, -1, 7, 0);
+/// let b = Vec4i::new(0b1010, 5, 0, -1);
+/// assert_eq!(a | b, [0b1110, -1, 7, -1]);
+/// ```
+impl core::ops::BitOr for Vec4i {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_or_si128(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Bitwise xor of two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(0b1100, -1, 7, 0);
+/// let b = Vec4i::new(0b1010, 5, 0, -1);
+/// assert_eq!(a ^ b, [0b0110, !5, 7, -1]);
+/// ```
+impl core::ops::BitXor for Vec4i {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_xor_si128(self.xmm, other.xmm) },
+        }
+    }
+}
+
+/// Bitwise not of the vector
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(0, -1, 5, i32::MIN);
+/// assert_eq!(!a, [-1, 0, !5, i32::MAX]);
+/// ```
+impl core::ops::Not for Vec4i {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self {
+            // SAFETY: sse2
+            xmm: unsafe { _mm_xor_si128(self.xmm, _mm_set1_epi32(-1)) },
+        }
+    }
+}
+
+/// Operator ==, compares two vectors
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let a = Vec4i::new(-1, 2, 3, 4);
+/// let b = Vec4i::new(-1, 2, 3, 4);
+/// assert_eq!(a, b);
+///
+/// let c = Vec4i::new(-1, 2, 5, 4);
+/// assert_ne!(a, c);
+/// ```
+impl core::cmp::PartialEq for Vec4i {
+    fn eq(&self, other: &Self) -> bool {
+        // SAFETY: sse2
+        let comparison: i32 = unsafe { _mm_movemask_epi8(_mm_cmpeq_epi32(self.xmm, other.xmm)) };
+        comparison == 0xFFFF
+    }
+}
+
+/// Operator ==, compares vector to [i32; 4]
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let vec = Vec4i::new(1, 2, 3, 4);
+/// assert_eq!(vec, [1, 2, 3, 4]);
+/// ```
+impl core::cmp::PartialEq<[i32; 4]> for Vec4i {
+    fn eq(&self, other: &[i32; 4]) -> bool {
+        let mut vec = Vec4i::default();
+        vec.load(other);
+        self.eq(&vec)
+    }
+}
+
+/// Reinterprets vector as `[i32; 4]` and formats it as a debug string
+///
+/// # Examples
+///
+/// ```
+/// use vcl_rust::Vec4i;
+///
+/// let vec = Vec4i::new(1, 2, 3, 4);
+/// println!("{:?}", vec);
+/// ```
+impl core::fmt::Debug for Vec4i {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut arr = [0i32; 4];
+        self.store(&mut arr);
+        arr.fmt(f)
+    }
+}